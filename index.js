@@ -43,7 +43,7 @@ if (require.main === module) {
         case ('stat'):
         case ('stats'): {
             const stat_arg = require('minimist')(process.argv, {
-                string: ['input', 'bounds'],
+                string: ['input', 'bounds', 'previous', 'csv', 'geojson'],
                 alias: {
                     bounds: 'bound'
                 }
@@ -51,7 +51,10 @@ if (require.main === module) {
 
             const stats = require('./native/index.node').stats({
                 input: stat_arg.input ? stat_arg.input : stat_arg._[3],
-                bounds: stat_arg.bounds
+                bounds: stat_arg.bounds,
+                previous: stat_arg.previous,
+                csv: stat_arg.csv,
+                geojson: stat_arg.geojson
             });
 
             console.log(JSON.stringify(stats));
@@ -161,8 +164,8 @@ if (require.main === module) {
         }
         case ('dedupe'): {
             const dedupe_arg = require('minimist')(process.argv, {
-                string: ['buildings', 'input', 'output', 'languages', 'db', 'country', 'region'],
-                boolean: ['hecate'],
+                string: ['buildings', 'input', 'output', 'languages', 'synonyms', 'tokens', 'policy', 'db', 'country', 'region', 'radius', 'survivor', 'sources', 'report'],
+                boolean: ['hecate', 'fuzzy', 'merge'],
                 alias: {
                     database: 'db'
                 }
@@ -178,6 +181,12 @@ if (require.main === module) {
                 input: dedupe_arg.input,
                 output: dedupe_arg.output,
                 hecate: dedupe_arg.hecate,
+                fuzzy: dedupe_arg.fuzzy,
+                radius: dedupe_arg.radius ? parseFloat(dedupe_arg.radius) : undefined,
+                survivor: dedupe_arg.survivor,
+                sources: dedupe_arg.sources ? dedupe_arg.sources.split(',') : undefined,
+                merge: dedupe_arg.merge,
+                report: dedupe_arg.report,
                 context: new Context(dedupe_arg).as_json(),
                 db: dedupe_arg.db
             });
@@ -186,12 +195,14 @@ if (require.main === module) {
         }
         case ('classify'): {
             const classify_arg = require('minimist')(process.argv, {
-                string: ['buildings', 'parcels', 'input', 'output', 'db'],
-                boolean: ['hecate'],
+                string: ['buildings', 'parcels', 'input', 'output', 'db', 'interpolated', 'entrance', 'building_tolerance', 'parcel_tolerance'],
+                boolean: ['hecate', 'snap'],
                 alias: {
                     database: 'db',
                     buildings: 'building',
-                    parcels: 'parcel'
+                    parcels: 'parcel',
+                    'building_tolerance': 'building-tolerance',
+                    'parcel_tolerance': 'parcel-tolerance'
                 }
             });
 
@@ -215,7 +226,12 @@ if (require.main === module) {
                 input: classify_arg.input,
                 output: classify_arg.output,
                 db: classify_arg.db,
-                hecate: classify_arg.hecate
+                hecate: classify_arg.hecate,
+                interpolated: classify_arg.interpolated ? classify_arg.interpolated.split(',') : undefined,
+                entrance: classify_arg.entrance ? parseFloat(classify_arg.entrance) : undefined,
+                building_tolerance: classify_arg.building_tolerance ? parseFloat(classify_arg.building_tolerance) : undefined,
+                parcel_tolerance: classify_arg.parcel_tolerance ? parseFloat(classify_arg.parcel_tolerance) : undefined,
+                snap: classify_arg.snap
             });
 
             break;
@@ -5,11 +5,13 @@ module.exports = function(argv) {
         case ('classify'):
             console.log('');
             console.log('Given a parcel &/ a building polygon layer, classify address points as one of');
-            console.log('  point, building, parcel');
+            console.log('  interpolated, entrance, rooftop, parcel, point');
             console.log('');
             console.log('usage: index.js classify [--buildings <buildings.geojson> ] [--parcels <parcels.geojson> ]');
             console.log('                         [--input|-i <addresses.geojson>] [--output|-o <OUTFILE>]');
-            console.log('                         [--database|--db <DATABASE>] [--hecate]');
+            console.log('                         [--database|--db <DATABASE>] [--hecate] [--interpolated <SOURCE,...>]');
+            console.log('                         [--entrance <METERS>] [--building-tolerance <METERS>] [--parcel-tolerance <METERS>]');
+            console.log('                         [--snap]');
             console.log('');
             console.log('[options]:');
             console.log('   --input|-i <FILE>         Addresses to classify in PT2ITP Format');
@@ -18,6 +20,12 @@ module.exports = function(argv) {
             console.log('   --buildings <FILE>        GeoJSONLD Building Polygons');
             console.log('   --parcels <FILE>          GeoJSONLD Parcel Polygons');
             console.log('   --hecate                  Expect Hecate input & produce hecate output');
+            console.log('   --interpolated <SOURCE,...> Sources of points generated by pt2itp to classify as interpolated');
+            console.log('   --entrance <METERS>       Max distance from a building outline to classify as entrance (default 0.5)');
+            console.log('   --building-tolerance <METERS> Max distance outside of a building to classify as rooftop (default 0)');
+            console.log('   --parcel-tolerance <METERS>   Max distance outside of a parcel to classify as parcel (default 0)');
+            console.log('   --snap                    Move parcel & point addresses to the single or largest building in their');
+            console.log('                               parcel as rooftop, storing the original position in snapped_from');
             break;
         case ('testcsv'):
             console.log('');
@@ -66,11 +74,15 @@ module.exports = function(argv) {
             console.log('');
             console.log('Generate stats about addresses in the computed ITP file or the address/network input files');
             console.log('');
-            console.log('usage index.js stats <GeoJSON> [--bounds <Boundaries File>]');
+            console.log('usage index.js stats <GeoJSON> [--bounds <Boundaries File>] [--previous <GeoJSON>]');
+            console.log('                      [--csv <FILE>] [--geojson <FILE>]');
             console.log('');
             console.log('[options]:');
             console.log('   <ITP GeoJSON>                Generated ITP data');
             console.log('   --bounds <FILE>              [optional] Given a set of line delimited GeoJSON polygons, output stats for each');
+            console.log('   --previous <ITP GeoJSON>     [optional] Previously generated ITP data, output the changes between the two');
+            console.log('   --csv <FILE>                 [optional] Write a CSV row of counts per bound (requires --bounds)');
+            console.log('   --geojson <FILE>             [optional] Write the per bound counts joined to the bound geometries (requires --bounds)');
             console.log();
             break;
         case ('map'):
@@ -92,6 +104,9 @@ module.exports = function(argv) {
             console.log('          discard-bad-orphans                   Discard orphan address clusters with only one address and only numeric,');
             console.log('                                                  only punctuation carmen:text, or no override:postcode property');
             console.log('   --languages=<ISO 639-1,ISO 639-1,...>    [optional] Language codes of abbreviation tokens to match');
+            console.log('   --synonyms=<FILE>                        [optional] JSON file of regex synonym rules to apply by country/region');
+            console.log('   --tokens=<FILE>                          [optional] JSON file of custom abbreviation tokens to merge over --languages');
+            console.log('   --policy=<FILE>                          [optional] JSON file of name ranking policy overrides, choosing the primary name');
             console.log('   --props=<Prop,Prop,...>                  [optional] List of properties to output that were included in');
             console.log('                                                the input GeoJSON');
             console.log('   --country=<ISO3166-1 Alpha2>             [optional] Optionally populate carmen:geocoder_stack');
@@ -117,6 +132,9 @@ module.exports = function(argv) {
             console.log('   --output=<FILE.geojson>                   output line-delimited geojson diff of new addresses');
             console.log('   --error-persistent=<FILE>                 [optional] File to log persistent address import errors');
             console.log('   --languages=<ISO 639-1,ISO 639-1,...>     [optional] Language codes of abbreviation tokens to match');
+            console.log('   --synonyms=<FILE>                         [optional] JSON file of regex synonym rules to apply by country/region');
+            console.log('   --tokens=<FILE>                           [optional] JSON file of custom abbreviation tokens to merge over --languages');
+            console.log('   --policy=<FILE>                           [optional] JSON file of name ranking policy overrides, choosing the primary name');
             console.log('   --country=<ISO3166-1 Alpha2>              [optional]');
             console.log('   --region=<ISO3166-2>                      [optional]');
             break;
@@ -125,7 +143,8 @@ module.exports = function(argv) {
             console.log('Remove duplicates witin a given address file');
             console.log('');
             console.log('usage: index.js dedupe [--input=<FILE>] [--output <FILE>] [--languages=<CODE, ...>]');
-            console.log('                   [--db <DATABASE] [--hecate] [--buildings=<FILE>]');
+            console.log('                   [--db <DATABASE] [--hecate] [--buildings=<FILE>] [--fuzzy] [--radius=<METERS>]');
+            console.log('                   [--survivor=<POLICY>] [--sources=<SOURCE, ...>] [--merge] [--report=<FILE>]');
             console.log('');
             console.log('Note: by default will read from STDIN and output to STDOUT');
             console.log('');
@@ -133,10 +152,27 @@ module.exports = function(argv) {
             console.log('   --db <DATABASE>                           [optional] Postgres database to run analysis in');
             console.log('   --input=<FILE.geojson>                    [optional] line-delimited geojson of address features');
             console.log('   --buildings=<FILE.geojson>                [optional] line-delimited geojson of polygonal buildings');
-            console.log('                                               to use as bounds for deduping');
+            console.log('                                               to use as bounds for deduping. Points with the same number');
+            console.log('                                               & street in a single building are considered duplicates');
             console.log('   --output=<FILE.geojson>                   [optional] line-delimited geojson of deduped features to write to');
             console.log('   --languages=<ISO 639-1,ISO 639-1,...>     [optional] Language codes of abbreviation tokens to match');
+            console.log('   --synonyms=<FILE>                         [optional] JSON file of regex synonym rules to apply by country/region');
+            console.log('   --tokens=<FILE>                           [optional] JSON file of custom abbreviation tokens to merge over --languages');
+            console.log('   --policy=<FILE>                           [optional] JSON file of name ranking policy overrides, choosing the primary name');
             console.log('   --hecate                                  [optional] output duplicates as hecate deletions');
+            console.log('   --fuzzy                                   [optional] also remove near-duplicates with linked names and');
+            console.log('                                               standardized numbers (Main St vs Main Street, 10 A vs 10a)');
+            console.log('   --radius=<METERS>                         [optional] radius to search for fuzzy duplicates within (default 10)');
+            console.log('   --survivor=<POLICY>                       [optional] feature to keep from a group of duplicates');
+            console.log('          id                                    lowest id (default)');
+            console.log('          accuracy                              highest accuracy property (rooftop, entrance, parcel, point, interpolated)');
+            console.log('          version                               newest version');
+            console.log('          source                                earliest source in --sources');
+            console.log('          names                                 most street name synonyms');
+            console.log('   --sources=<SOURCE,SOURCE,...>             [optional] preferred sources, most preferred first');
+            console.log('   --merge                                   [optional] merge names & props of duplicates into the survivor');
+            console.log('   --report=<FILE.jsonl>                     [optional] line-delimited JSON audit report of each duplicate');
+            console.log('                                               group, its members, sources, distances & survivor');
             break;
         case ('strip'):
             console.log('');
@@ -176,6 +212,9 @@ module.exports = function(argv) {
             console.log('   --error-sources=<FILE>                    [optional] File to log source address import errors');
             console.log('   --error-query-points=<FILE>               [optional] File to log query point import errors');
             console.log('   --languages=<ISO 639-1,ISO 639-1,...>     [optional] Language codes of abbreviation tokens to match');
+            console.log('   --synonyms=<FILE>                         [optional] JSON file of regex synonym rules to apply by country/region');
+            console.log('   --tokens=<FILE>                           [optional] JSON file of custom abbreviation tokens to merge over --languages');
+            console.log('   --policy=<FILE>                           [optional] JSON file of name ranking policy overrides, choosing the primary name');
             console.log('   --country=<ISO3166-1 Alpha2>              [optional]');
             console.log('   --region=<ISO3166-2>                      [optional]');
             break;
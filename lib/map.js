@@ -150,7 +150,7 @@ function main(argv, cb) {
      */
     function matcher() {
         console.time('ok - cross matched data');
-        link_addr(argv.db);
+        link_addr(argv.db, argv.country);
         console.timeEnd('ok - cross matched data');
 
         console.time('ok - clustered addresses');
@@ -240,8 +240,10 @@ function main(argv, cb) {
                         props: argv.props,
                         post: argv.post,
                         country: argv.country,
+                        region: argv.region,
                         debug: argv.debug,
                         languages: argv.languages,
+                        policy: argv.policy,
                         intersections: argv.intersections,
                         pool: poolConf
                     };
@@ -39,6 +39,10 @@ class Post {
             });
         }
 
+        if (args.policy) {
+            this.opts.ranking = JSON.parse(fs.readFileSync(args.policy));
+        }
+
         if (opts.noDefaults) {
             this.posts = [];
         } else {
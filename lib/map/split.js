@@ -201,6 +201,7 @@ class Split {
      * @param {boolean} o.stdout Turn off stdout - true by default
      * @param {Array} o.post Array of non-default post operations to perform on output
      * @param {Array} o.props Properties to output on Cluster Geometries
+     * @param {string} o.policy Path to a JSON name ranking policy file
      */
     constructor(o) {
         this.opts = o;
@@ -214,7 +215,8 @@ class Split {
         this.props = o.props;
         this.post = new Post(this.opts, {
             intersections: this.opts.intersections,
-            props: this.opts.props
+            props: this.opts.props,
+            policy: this.opts.policy
         });
     }
 
@@ -47,7 +47,7 @@ function post(feat, opts) {
 
     for (const intersection of intersections) {
         if (!intersection || !intersection.street) continue;
-        const streets = dedupe_syn(intersection.street);
+        const streets = dedupe_syn(intersection.street, opts.ranking, opts.country, opts.region);
         for (const st of streets) {
             if (final.indexOf(st) === -1) {
                 final.push(st);
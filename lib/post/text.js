@@ -12,13 +12,24 @@ const { dedupe_syn } = require('../../native/index.node');
 function post(feat, opts = {}) {
     if (!feat || !feat.properties || !feat.properties['carmen:text']) return feat;
 
+    // Names tagged with a language are also output in their language specific carmen:text_xx tag
+    if (Array.isArray(feat.properties['carmen:text'])) {
+        for (const name of feat.properties['carmen:text']) {
+            if (!name.language) continue;
+
+            const k = `carmen:text_${name.language}`;
+            if (!feat.properties[k]) feat.properties[k] = [];
+            if (Array.isArray(feat.properties[k])) feat.properties[k].push(name);
+        }
+    }
+
     Object.keys(feat.properties)
         .filter((k) => {
             // Support multi-lingual carmen:text tags
             return k.indexOf('carmen:text') === 0;
         })
         .forEach((k) => {
-            const names = dedupe_syn(feat.properties[k]);
+            const names = dedupe_syn(feat.properties[k], opts.ranking, opts.country, opts.region);
 
             if (names.length > 10) {
                 if (opts.warn) {
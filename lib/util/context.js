@@ -16,9 +16,15 @@ class Context {
         this.country = '';
         this.region = '';
         this.languages = [];
+        this.synonyms = null;
+        this.tokens = null;
+        this.policy = null;
 
         if (args.country) this.country = args.country;
         if (args.region) this.region = args.region;
+        if (args.synonyms) this.synonyms = args.synonyms;
+        if (args.tokens) this.tokens = args.tokens;
+        if (args.policy) this.policy = args.policy;
 
         if (args.languages) {
             if (typeof args.languages === 'string') {
@@ -38,11 +44,17 @@ class Context {
      * @return {Object}
      */
     as_json() {
-        return {
+        const json = {
             country: this.country,
             region: this.region,
             languages: this.languages
         };
+
+        if (this.synonyms) json.synonyms = this.synonyms;
+        if (this.tokens) json.tokens = this.tokens;
+        if (this.policy) json.policy = this.policy;
+
+        return json;
     }
 
     /**
@@ -69,6 +81,18 @@ class Context {
             minimist.string.push('region');
         }
 
+        if (!minimist.string.includes('synonyms')) {
+            minimist.string.push('synonyms');
+        }
+
+        if (!minimist.string.includes('tokens')) {
+            minimist.string.push('tokens');
+        }
+
+        if (!minimist.string.includes('policy')) {
+            minimist.string.push('policy');
+        }
+
         minimist.alias.languages = 'language';
 
         return minimist;
@@ -1,6 +1,5 @@
 use postgres::{Connection, TlsMode};
 use std::{
-    collections::HashMap,
     convert::From,
     fs::File,
     io::{BufWriter, Write},
@@ -23,6 +22,11 @@ struct ClassifyArgs {
     parcels: Option<String>,
     input: Option<String>,
     output: Option<String>,
+    interpolated: Option<Vec<String>>,
+    entrance: Option<f64>,
+    building_tolerance: Option<f64>,
+    parcel_tolerance: Option<f64>,
+    snap: Option<bool>,
 }
 
 impl ClassifyArgs {
@@ -34,6 +38,11 @@ impl ClassifyArgs {
             parcels: None,
             input: None,
             output: None,
+            interpolated: None,
+            entrance: None,
+            building_tolerance: None,
+            parcel_tolerance: None,
+            snap: None,
         }
     }
 }
@@ -55,6 +64,15 @@ pub fn classify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
     let is_hecate = args.hecate.unwrap_or(false);
 
+    // Distances are all given in meters
+    //
+    // entrance: max distance from a building outline to be considered an entrance
+    // building_tolerance: max distance outside of a building to be considered rooftop
+    // parcel_tolerance: max distance outside of a parcel to be considered parcel
+    let entrance = args.entrance.unwrap_or(0.5);
+    let building_tolerance = args.building_tolerance.unwrap_or(0.0);
+    let parcel_tolerance = args.parcel_tolerance.unwrap_or(0.0);
+
     let mut output = match args.output {
         None => panic!("Output file required"),
         Some(output) => match File::create(output) {
@@ -75,11 +93,7 @@ pub fn classify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
         &conn,
         AddrStream::new(
             GeoStream::new(args.input),
-            crate::Context::new(
-                String::from("xx"),
-                None,
-                Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-            ),
+            crate::Context::new(String::from("xx"), None, Tokens::default()),
             None,
         ),
     );
@@ -125,48 +139,75 @@ pub fn classify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     conn.execute(
         "
         ALTER TABLE address
-            ADD COLUMN accuracy TEXT
+            ADD COLUMN accuracy TEXT,
+            ADD COLUMN snapped BOOLEAN NOT NULL DEFAULT FALSE
     ",
         &[],
     )
     .unwrap();
 
+    // Points generated by pt2itp were never observed, regardless of location
+    let interpolated = args.interpolated.unwrap_or(Vec::new());
+    if !interpolated.is_empty() {
+        conn.execute(
+            "
+            UPDATE address
+                SET
+                    accuracy = 'interpolated'
+                WHERE
+                    source = ANY($1)
+        ",
+            &[&interpolated],
+        )
+        .unwrap();
+        println!("ok - calculated accuracy: interpolated");
+    }
+
     conn.execute(
-        "
+        format!(
+            "
         UPDATE address
             SET
-                accuracy = 'rooftop'
+                accuracy = 'entrance'
             FROM
                 buildings
             WHERE
-                ST_Intersects(address.geom, buildings.geom)
-    ",
-        &[],
-    )
-    .unwrap();
-    println!("ok - calculated accuracy: building");
-
-    conn.execute(
-        "
-        ALTER TABLE parcels
-            ADD COLUMN centroid GEOMETRY(POINT, 4326)
+                accuracy IS NULL
+                AND {within}
+                AND ST_DWithin(address.geom::GEOGRAPHY, ST_Boundary(buildings.geom)::GEOGRAPHY, {entrance})
     ",
+            within = within("address.geom", "buildings.geom", entrance),
+            entrance = entrance
+        )
+        .as_str(),
         &[],
     )
     .unwrap();
+    println!("ok - calculated accuracy: entrance");
 
     conn.execute(
-        "
-        UPDATE parcels
-            SET centroid = ST_PointOnSurface(parcels.geom)
+        format!(
+            "
+        UPDATE address
+            SET
+                accuracy = 'rooftop'
+            FROM
+                buildings
+            WHERE
+                accuracy IS NULL
+                AND {within}
     ",
+            within = within("address.geom", "buildings.geom", building_tolerance)
+        )
+        .as_str(),
         &[],
     )
     .unwrap();
-    println!("ok - calculated parcel centroids");
+    println!("ok - calculated accuracy: building");
 
     conn.execute(
-        "
+        format!(
+            "
         UPDATE address
             SET
                 accuracy = 'parcel'
@@ -174,8 +215,11 @@ pub fn classify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
                 parcels
             WHERE
                 accuracy IS NULL
-                AND ST_DWithin(address.geom, parcels.centroid, 0.0001)
+                AND {within}
     ",
+            within = within("address.geom", "parcels.geom", parcel_tolerance)
+        )
+        .as_str(),
         &[],
     )
     .unwrap();
@@ -194,6 +238,43 @@ pub fn classify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     .unwrap();
     println!("ok - calculated accuracy: point");
 
+    if args.snap.unwrap_or(false) {
+        // Move parcel & point accuracy addresses to the single (or largest)
+        // building within their parcel, retaining the original position
+        conn.execute(
+            r#"
+            UPDATE address
+                SET
+                    props = props::JSONB || JSON_Build_Object(
+                        'snapped_from', JSON_Build_Array(ST_X(address.geom), ST_Y(address.geom))
+                    )::JSONB,
+                    geom = ST_SetSRID(ST_MakePoint(ST_X(snap.geom), ST_Y(snap.geom), ST_Z(address.geom)), 4326),
+                    accuracy = 'rooftop',
+                    snapped = TRUE
+                FROM (
+                    SELECT DISTINCT ON (a.id)
+                        a.id,
+                        ST_PointOnSurface(b.geom) AS geom
+                    FROM
+                        address a
+                        INNER JOIN parcels p ON ST_Intersects(a.geom, p.geom)
+                        INNER JOIN buildings b ON ST_Intersects(p.geom, b.geom)
+                    WHERE
+                        a.accuracy IN ('parcel', 'point')
+                        AND ST_Intersects(p.geom, ST_PointOnSurface(b.geom))
+                    ORDER BY
+                        a.id,
+                        ST_Area(b.geom) DESC
+                ) snap
+                WHERE
+                    address.id = snap.id
+        "#,
+            &[],
+        )
+        .unwrap();
+        println!("ok - snapped addresses to buildings");
+    }
+
     let modified = match is_hecate {
         true => {
             conn.execute(
@@ -203,6 +284,7 @@ pub fn classify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
                         accuracy = NULL
                     WHERE
                         accuracy = props->>'accuracy'
+                        AND NOT snapped
             "#,
                 &[],
             )
@@ -290,3 +372,15 @@ pub fn classify(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
     Ok(cx.boolean(true))
 }
+
+///
+/// SQL condition for a point being within the given meters of a polygon,
+/// a distance of 0 only considers points that intersect the polygon
+///
+fn within(point: &str, poly: &str, meters: f64) -> String {
+    if meters <= 0.0 {
+        format!("ST_Intersects({}, {})", point, poly)
+    } else {
+        pg::dwithin_meters(point, poly, meters)
+    }
+}
@@ -1,7 +1,6 @@
 use geojson::GeoJson;
 use postgres::{Connection, TlsMode};
-use std::collections::HashMap;
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::fs::File;
 use std::io::{BufWriter, Write};
 
@@ -113,12 +112,13 @@ pub fn conflate(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     .unwrap();
 
     let context = match args.context {
-        Some(context) => crate::Context::from(context),
-        None => crate::Context::new(
-            String::from(""),
-            None,
-            crate::Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        ),
+        Some(context) => match crate::Context::try_from(context) {
+            Ok(context) => context,
+            Err(err) => {
+                return cx.throw_error(format!("conflate - invalid context: {}", err));
+            }
+        },
+        None => crate::Context::new(String::from(""), None, crate::Tokens::default()),
     };
 
     let pgaddress = pg::Address::new();
@@ -173,7 +173,7 @@ pub fn conflate(mut cx: FunctionContext) -> JsResult<JsBoolean> {
             persistents.push(paddr);
         }
 
-        match compare(&addr, &mut persistents) {
+        match compare(&addr, &mut persistents, &context.country) {
             // persistent address matches new address, consider modifying persistent address
             Some(link_id) => {
                 let mut pmatches: Vec<&mut Address> = persistents
@@ -196,7 +196,7 @@ pub fn conflate(mut cx: FunctionContext) -> JsResult<JsBoolean> {
                             combined_names.concat(paddr.names.clone());
                             combined_names.empty();
                             combined_names.sort();
-                            combined_names.dedupe();
+                            combined_names.dedupe(&context.country);
 
                             let mut new_names: Vec<InputName> =
                                 Vec::with_capacity(combined_names.names.len());
@@ -287,7 +287,7 @@ pub fn conflate(mut cx: FunctionContext) -> JsResult<JsBoolean> {
             }
             names_base.empty();
             names_base.sort();
-            names_base.dedupe();
+            names_base.dedupe(&context.country);
             let mut names_final = Vec::with_capacity(names_base.names.len());
             for name in names_base.names {
                 names_final.push(InputName::from(name));
@@ -326,7 +326,7 @@ pub fn conflate(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 ///
 /// The function will return Some(i64) if the address matches an existing address
 ///
-pub fn compare(potential: &Address, persistents: &mut Vec<Address>) -> Option<i64> {
+pub fn compare(potential: &Address, persistents: &mut Vec<Address>, country: &str) -> Option<i64> {
     // The address does not exist in the database and should be created
     if persistents.len() == 0 {
         return None;
@@ -337,7 +337,7 @@ pub fn compare(potential: &Address, persistents: &mut Vec<Address>) -> Option<i6
         .map(|persistent| linker::Link::new(persistent.id.unwrap(), &persistent.names))
         .collect();
 
-    match linker::linker(potential_link, persistent_links, true) {
+    match linker::linker(potential_link, persistent_links, true, country) {
         Some(link) => Some(link.id),
         None => None,
     }
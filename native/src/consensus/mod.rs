@@ -1,6 +1,6 @@
 use postgres::{Connection, TlsMode};
 use std::collections::HashMap;
-use std::convert::From;
+use std::convert::{From, TryFrom};
 
 mod agreement;
 
@@ -84,12 +84,13 @@ pub fn consensus(mut cx: FunctionContext) -> JsResult<JsValue> {
     .unwrap();
 
     let context = match args.context {
-        Some(context) => crate::Context::from(context),
-        None => crate::Context::new(
-            String::from(""),
-            None,
-            crate::Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        ),
+        Some(context) => match crate::Context::try_from(context) {
+            Ok(context) => context,
+            Err(err) => {
+                return cx.throw_error(format!("consensus - invalid context: {}", err));
+            }
+        },
+        None => crate::Context::new(String::from(""), None, crate::Tokens::default()),
     };
 
     let pgaddress = pg::Address::new();
@@ -166,7 +167,7 @@ pub fn consensus(mut cx: FunctionContext) -> JsResult<JsValue> {
             }
 
             // find a match using linker
-            match compare(&addr, &mut potential_matches) {
+            match compare(&addr, &mut potential_matches, &context.country) {
                 Some(link_id) => {
                     let mut pmatches: Vec<Address> = potential_matches
                         .into_iter()
@@ -209,7 +210,7 @@ pub fn consensus(mut cx: FunctionContext) -> JsResult<JsValue> {
 ///
 /// The function will return Some(i64) if the address matches an existing address
 ///
-pub fn compare(addr: &Address, potentials: &mut Vec<Address>) -> Option<i64> {
+pub fn compare(addr: &Address, potentials: &mut Vec<Address>, country: &str) -> Option<i64> {
     // No nearby addresses with this number
     if potentials.len() == 0 {
         return None;
@@ -220,5 +221,5 @@ pub fn compare(addr: &Address, potentials: &mut Vec<Address>) -> Option<i64> {
         .map(|potential| linker::Link::new(potential.id.unwrap(), &potential.names))
         .collect();
 
-    linker::linker(addr_link, potential_links, true).map(|link| link.id)
+    linker::linker(addr_link, potential_links, true, country).map(|link| link.id)
 }
@@ -1,6 +1,7 @@
+use geo::algorithm::haversine_distance::HaversineDistance;
 use postgres::{Connection, TlsMode};
 use std::collections::HashMap;
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::thread;
@@ -10,6 +11,7 @@ use neon::prelude::*;
 use crate::{
     stream::{AddrStream, GeoStream, PolyStream},
     types::hecate,
+    util::linker,
     Address,
 };
 
@@ -24,6 +26,12 @@ struct DedupeArgs {
     input: Option<String>,
     output: Option<String>,
     hecate: Option<bool>,
+    fuzzy: Option<bool>,
+    radius: Option<f64>,
+    survivor: Option<Survivor>,
+    sources: Option<Vec<String>>,
+    merge: Option<bool>,
+    report: Option<String>,
 }
 
 impl DedupeArgs {
@@ -35,10 +43,94 @@ impl DedupeArgs {
             input: None,
             output: None,
             hecate: None,
+            fuzzy: None,
+            radius: None,
+            survivor: None,
+            sources: None,
+            merge: None,
+            report: None,
         }
     }
 }
 
+///
+/// Determines which feature within a group of duplicates is retained
+///
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Survivor {
+    /// Lowest id
+    Id,
+    /// Highest accuracy property (rooftop > entrance > parcel > point > interpolated)
+    Accuracy,
+    /// Newest version
+    Version,
+    /// Earliest source in the preferred source ranking
+    Source,
+    /// Most street name synonyms
+    Names,
+}
+
+#[derive(Debug, Clone)]
+pub struct SurvivorPolicy {
+    pub survivor: Survivor,
+
+    /// Preferred sources, most preferred first
+    pub sources: Vec<String>,
+
+    /// Should the names & props of removed features be merged into the survivor
+    pub merge: bool,
+
+    /// Country of the features, merged names being deduped as in the country
+    pub country: String,
+}
+
+impl SurvivorPolicy {
+    ///
+    /// Order two features by preference, Less being the
+    /// preferred feature. Ties fall back to the lowest id
+    ///
+    pub fn cmp(&self, a: &Address, b: &Address) -> std::cmp::Ordering {
+        let order = match self.survivor {
+            Survivor::Id => std::cmp::Ordering::Equal,
+            Survivor::Accuracy => accuracy_rank(a).cmp(&accuracy_rank(b)),
+            Survivor::Version => b.version.cmp(&a.version),
+            Survivor::Source => self.source_rank(a).cmp(&self.source_rank(b)),
+            Survivor::Names => b.names.names.len().cmp(&a.names.names.len()),
+        };
+
+        order.then(a.id.cmp(&b.id))
+    }
+
+    fn source_rank(&self, addr: &Address) -> usize {
+        match self
+            .sources
+            .iter()
+            .position(|source| source == &addr.source)
+        {
+            Some(rank) => rank,
+            None => self.sources.len(),
+        }
+    }
+}
+
+///
+/// Rank the accuracy property of a feature, lower being more accurate
+///
+fn accuracy_rank(addr: &Address) -> usize {
+    match addr.props.get("accuracy") {
+        Some(serde_json::Value::String(accuracy)) => match accuracy.as_str() {
+            "rooftop" => 0,
+            "entrance" => 1,
+            "parcel" => 2,
+            "point" => 3,
+            "interpolated" => 4,
+            _ => 5,
+        },
+        _ => 5,
+    }
+}
+
 pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let args: DedupeArgs = match cx.argument_opt(0) {
         None => DedupeArgs::new(),
@@ -56,6 +148,12 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
     let is_hecate = args.hecate.unwrap_or(false);
 
+    // Radius in meters within which near-duplicates will be considered
+    let fuzzy: Option<f64> = match args.fuzzy {
+        Some(true) => Some(args.radius.unwrap_or(10.0)),
+        _ => None,
+    };
+
     let conn = Connection::connect(
         format!("postgres://postgres@localhost:5432/{}", &args.db).as_str(),
         TlsMode::None,
@@ -63,19 +161,20 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     .unwrap();
 
     let context = match args.context {
-        Some(context) => crate::Context::from(context),
-        None => crate::Context::new(
-            String::from(""),
-            None,
-            crate::Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        ),
+        Some(context) => match crate::Context::try_from(context) {
+            Ok(context) => context,
+            Err(err) => {
+                return cx.throw_error(format!("dedupe - invalid context: {}", err));
+            }
+        },
+        None => crate::Context::new(String::from(""), None, crate::Tokens::default()),
     };
 
     let address = pg::Address::new();
     address.create(&conn);
     address.input(
         &conn,
-        AddrStream::new(GeoStream::new(args.input), context, None),
+        AddrStream::new(GeoStream::new(args.input), context.clone(), None),
     );
 
     if !is_hecate {
@@ -86,7 +185,7 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
     address.index(&conn);
 
-    match args.buildings {
+    let has_buildings = match args.buildings {
         Some(buildings) => {
             let polygon = pg::Polygon::new(String::from("buildings"));
             polygon.create(&conn);
@@ -94,9 +193,30 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
                 &conn,
                 PolyStream::new(GeoStream::new(Some(buildings)), None),
             );
+            polygon.seq_id(&conn);
             polygon.index(&conn);
+
+            address.buildings(&conn, "buildings");
+
+            true
         }
-        None => (),
+        None => false,
+    };
+
+    // Only report the tier if a tier other than exact is enabled
+    let tiered = fuzzy.is_some() || has_buildings;
+
+    let tiers = Tiers {
+        fuzzy: fuzzy,
+        buildings: has_buildings,
+        country: context.country.to_lowercase(),
+    };
+
+    let policy = SurvivorPolicy {
+        survivor: args.survivor.unwrap_or(Survivor::Id),
+        sources: args.sources.unwrap_or(Vec::new()),
+        merge: args.merge.unwrap_or(false),
+        country: context.country.clone(),
     };
 
     let count = address.count(&conn);
@@ -110,10 +230,11 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
     for cpu in 0..cpus {
         let db_conn = args.db.clone();
+        let tiers = tiers.clone();
         let tx_n = tx.clone();
 
         let strand = match thread::Builder::new()
-            .name(format!("Exact Dup #{}", &cpu))
+            .name(format!("Dedupe #{}", &cpu))
             .spawn(move || {
                 let mut min_id = batch * cpu;
                 let max_id = batch * cpu + batch + batch_extra;
@@ -130,7 +251,7 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
                     Err(err) => panic!("Connection Error: {}", err.to_string()),
                 };
 
-                exact_batch(is_hecate, min_id, max_id, conn, tx_n);
+                dedupe_batch(min_id, max_id, &tiers, conn, tx_n);
             }) {
             Ok(strand) => strand,
             Err(err) => panic!("Thread Creation Error: {}", err.to_string()),
@@ -141,6 +262,14 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
     drop(tx);
 
+    let report = match args.report {
+        Some(reportpath) => match File::create(reportpath) {
+            Ok(reportfile) => Some(BufWriter::new(reportfile)),
+            Err(err) => panic!("Unable to write to report file: {}", err),
+        },
+        None => None,
+    };
+
     match args.output {
         Some(outpath) => {
             let outfile = match File::create(outpath) {
@@ -150,9 +279,23 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
                 }
             };
 
-            output(is_hecate, rx, BufWriter::new(outfile))
+            output(
+                is_hecate,
+                tiered,
+                &policy,
+                rx,
+                BufWriter::new(outfile),
+                report,
+            )
         }
-        None => output(is_hecate, rx, std::io::stdout().lock()),
+        None => output(
+            is_hecate,
+            tiered,
+            &policy,
+            rx,
+            std::io::stdout().lock(),
+            report,
+        ),
     }
 
     for strand in web {
@@ -162,33 +305,618 @@ pub fn dedupe(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     Ok(cx.boolean(true))
 }
 
-fn output(is_hecate: bool, receive: crossbeam::Receiver<Address>, mut sink: impl Write) {
-    for result in receive.iter() {
-        let result: String = match is_hecate {
-            true => geojson::GeoJson::Feature(result.to_geojson(hecate::Action::Delete, false))
-                .to_string(),
-            false => geojson::GeoJson::Feature(result.to_geojson(hecate::Action::None, false))
-                .to_string(),
+///
+/// The dedupe tier that determined a feature to be a duplicate
+///
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Tier {
+    /// Identical number & names within 0.00001 degrees
+    Exact,
+    /// Linked names & standardized numbers within the fuzzy radius
+    Fuzzy,
+    /// Linked names & identical number within the same building footprint
+    Building,
+}
+
+impl Tier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tier::Exact => "exact",
+            Tier::Fuzzy => "fuzzy",
+            Tier::Building => "building",
+        }
+    }
+}
+
+///
+/// A group of duplicate address features, the survivor being
+/// the feature that should be retained
+///
+#[derive(Debug)]
+pub struct DupGroup {
+    pub survivor: Address,
+    pub duplicates: Vec<(Address, Tier)>,
+
+    /// Have names or props been merged into the survivor
+    pub merged: bool,
+}
+
+impl DupGroup {
+    ///
+    /// Given a primary feature and the features determined to be duplicates of it,
+    /// select the survivor according to the given policy
+    ///
+    /// edges: tier of each pair of duplicates, keyed by (lowest id, highest id)
+    ///
+    pub fn new(
+        feat: Address,
+        dup_feats: Vec<Address>,
+        edges: &HashMap<(i64, i64), Tier>,
+        policy: &SurvivorPolicy,
+    ) -> Self {
+        let mut members: Vec<Address> = Vec::with_capacity(dup_feats.len() + 1);
+        members.push(feat);
+        members.extend(dup_feats);
+
+        let tier = |a: &Address, b: &Address| edges.get(&edge(a.id.unwrap(), b.id.unwrap()));
+
+        // Only members that are duplicates of every other member can survive,
+        // otherwise features that are not duplicates of the survivor would be removed
+        let mut best = 0;
+        for (i, member) in members.iter().enumerate() {
+            let candidate = members
+                .iter()
+                .enumerate()
+                .all(|(j, other)| i == j || tier(member, other).is_some());
+
+            if candidate && policy.cmp(member, &members[best]) == std::cmp::Ordering::Less {
+                best = i;
+            }
+        }
+
+        let mut survivor = members.remove(best);
+
+        // Tiers are relative to the survivor
+        let members: Vec<(Address, Tier)> = members
+            .into_iter()
+            .map(|member| {
+                let tier = *tier(&survivor, &member).unwrap();
+                (member, tier)
+            })
+            .collect();
+
+        let mut merged = false;
+        if policy.merge {
+            for (dup_feat, _) in members.iter() {
+                if survivor.names.has_diff(&dup_feat.names) {
+                    survivor.names.concat(dup_feat.names.clone());
+                    merged = true;
+                }
+
+                for (key, value) in dup_feat.props.iter() {
+                    if !survivor.props.contains_key(key) {
+                        survivor.props.insert(key.clone(), value.clone());
+                        merged = true;
+                    }
+                }
+            }
+
+            if merged {
+                survivor.names.empty();
+                survivor.names.sort();
+                survivor.names.dedupe(&policy.country);
+            }
+        }
+
+        DupGroup {
+            survivor: survivor,
+            duplicates: members,
+            merged: merged,
+        }
+    }
+
+    ///
+    /// Serialize the group as a single line audit report, listing every
+    /// member along with its distance in meters from the survivor
+    ///
+    pub fn to_report(&self) -> String {
+        let survivor_pt = geo::Point::new(self.survivor.geom[0], self.survivor.geom[1]);
+
+        let mut members: Vec<ReportMember> = Vec::with_capacity(self.duplicates.len() + 1);
+
+        members.push(ReportMember {
+            id: self.survivor.id,
+            source: self.survivor.source.clone(),
+            distance: 0.0,
+            tier: None,
+            survivor: true,
+        });
+
+        for (dup_feat, tier) in self.duplicates.iter() {
+            let dup_pt = geo::Point::new(dup_feat.geom[0], dup_feat.geom[1]);
+
+            members.push(ReportMember {
+                id: dup_feat.id,
+                source: dup_feat.source.clone(),
+                distance: (survivor_pt.haversine_distance(&dup_pt) * 100.0).round() / 100.0,
+                tier: Some(tier.as_str()),
+                survivor: false,
+            });
+        }
+
+        serde_json::to_string(&ReportGroup {
+            survivor: self.survivor.id,
+            members: members,
+        })
+        .unwrap()
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct ReportGroup {
+    survivor: Option<i64>,
+    members: Vec<ReportMember>,
+}
+
+#[derive(Serialize, Debug)]
+struct ReportMember {
+    id: Option<i64>,
+    source: String,
+    distance: f64,
+    tier: Option<&'static str>,
+    survivor: bool,
+}
+
+fn output(
+    is_hecate: bool,
+    tiered: bool,
+    policy: &SurvivorPolicy,
+    receive: crossbeam::Receiver<DupLinks>,
+    mut sink: impl Write,
+    mut report: Option<BufWriter<File>>,
+) {
+    let mut exact: i64 = 0;
+    let mut fuzzy: i64 = 0;
+    let mut building: i64 = 0;
+
+    let mut write = |group: DupGroup| {
+        for (_, tier) in &group.duplicates {
+            match tier {
+                Tier::Exact => exact += 1,
+                Tier::Fuzzy => fuzzy += 1,
+                Tier::Building => building += 1,
+            };
+        }
+
+        match report {
+            Some(ref mut report) if !group.duplicates.is_empty() => {
+                if report
+                    .write(format!("{}\n", group.to_report()).as_bytes())
+                    .is_err()
+                {
+                    panic!("Failed to write to report stream");
+                }
+            }
+            _ => (),
+        };
+
+        let results: Vec<String> = match is_hecate {
+            // If it is hecate output - delete all features
+            // but the desired feature, modifying it if it gained names or props
+            true => {
+                let mut results: Vec<String> = group
+                    .duplicates
+                    .into_iter()
+                    .map(|(mut dup_feat, tier)| {
+                        if tiered {
+                            dup_feat.props.insert(
+                                String::from("dedupe:tier"),
+                                serde_json::value::Value::String(String::from(tier.as_str())),
+                            );
+                        }
+
+                        geojson::GeoJson::Feature(
+                            dup_feat.to_geojson(hecate::Action::Delete, false),
+                        )
+                        .to_string()
+                    })
+                    .collect();
+
+                if group.merged {
+                    results.push(
+                        geojson::GeoJson::Feature(
+                            group.survivor.to_geojson(hecate::Action::Modify, false),
+                        )
+                        .to_string(),
+                    );
+                }
+
+                results
+            }
+            // If not hecate, only print the desired feature
+            false => vec![geojson::GeoJson::Feature(
+                group.survivor.to_geojson(hecate::Action::None, false),
+            )
+            .to_string()],
         };
 
-        if sink.write(format!("{}\n", result).as_bytes()).is_err() {
-            panic!("Failed to write to output stream");
+        for result in results {
+            if sink.write(format!("{}\n", result).as_bytes()).is_err() {
+                panic!("Failed to write to output stream");
+            }
+        }
+    };
+
+    let mut components = Components::new();
+
+    for links in receive.iter() {
+        if let Some(component) = components.add(links) {
+            for group in component.groups(policy) {
+                write(group);
+            }
+        }
+    }
+
+    // Only reached if duplicates were not found in both directions
+    for component in components.drain() {
+        for group in component.groups(policy) {
+            write(group);
         }
     }
 
+    drop(write);
+
     if sink.flush().is_err() {
         panic!("Failed to flush output stream");
     }
+
+    if let Some(mut report) = report {
+        if report.flush().is_err() {
+            panic!("Failed to flush report stream");
+        }
+    }
+
+    eprintln!(
+        "ok - removed {} exact, {} fuzzy & {} building duplicates",
+        exact, fuzzy, building
+    );
 }
 
-fn exact_batch(
-    is_hecate: bool,
+///
+/// A feature along with the ids & tiers of the features that are duplicates of it
+///
+#[derive(Debug)]
+pub struct DupLinks {
+    pub feat: Address,
+    pub dups: Vec<(i64, Tier)>,
+}
+
+///
+/// Duplicates are not transitive within a fuzzy radius, A & B and B & C being duplicates
+/// does not make A & C duplicates. Linked features are therefore collected into connected
+/// components, which are only grouped once every member has been received
+///
+#[derive(Debug, Default)]
+pub struct Components {
+    /// Union-find parent of each linked feature id
+    parent: HashMap<i64, i64>,
+
+    /// Ids of the members of each component, keyed by the root id of the component
+    members: HashMap<i64, Vec<i64>>,
+
+    /// Linked features received so far
+    feats: HashMap<i64, Address>,
+
+    /// Tier of each pair of duplicates
+    edges: HashMap<(i64, i64), Tier>,
+}
+
+impl Components {
+    pub fn new() -> Self {
+        Components::default()
+    }
+
+    ///
+    /// Add a feature & its duplicates, returning its component
+    /// if every member of the component has now been received
+    ///
+    pub fn add(&mut self, links: DupLinks) -> Option<Component> {
+        let id = links.feat.id.unwrap();
+
+        if links.dups.is_empty() && !self.parent.contains_key(&id) {
+            return Some(Component {
+                feats: vec![links.feat],
+                edges: HashMap::new(),
+            });
+        }
+
+        self.insert(id);
+        for (dup_id, tier) in links.dups {
+            self.insert(dup_id);
+            self.union(id, dup_id);
+            self.edges.entry(edge(id, dup_id)).or_insert(tier);
+        }
+        self.feats.insert(id, links.feat);
+
+        let root = self.find(id);
+        if self.members[&root]
+            .iter()
+            .all(|member| self.feats.contains_key(member))
+        {
+            Some(self.remove(root))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Remove all remaining components, including members that were never received
+    ///
+    pub fn drain(&mut self) -> Vec<Component> {
+        let roots: Vec<i64> = self.members.keys().cloned().collect();
+
+        roots.into_iter().map(|root| self.remove(root)).collect()
+    }
+
+    fn insert(&mut self, id: i64) {
+        if !self.parent.contains_key(&id) {
+            self.parent.insert(id, id);
+            self.members.insert(id, vec![id]);
+        }
+    }
+
+    fn find(&mut self, id: i64) -> i64 {
+        let mut root = id;
+        while self.parent[&root] != root {
+            root = self.parent[&root];
+        }
+
+        // Point every feature on the path directly at the root
+        let mut current = id;
+        while current != root {
+            let next = self.parent[&current];
+            self.parent.insert(current, root);
+            current = next;
+        }
+
+        root
+    }
+
+    fn union(&mut self, a: i64, b: i64) {
+        let a = self.find(a);
+        let b = self.find(b);
+
+        if a == b {
+            return;
+        }
+
+        // Merge the smaller component into the larger
+        let (root, child) = if self.members[&a].len() >= self.members[&b].len() {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        self.parent.insert(child, root);
+        let mut members = self.members.remove(&child).unwrap();
+        self.members.get_mut(&root).unwrap().append(&mut members);
+    }
+
+    fn remove(&mut self, root: i64) -> Component {
+        let members = self.members.remove(&root).unwrap();
+
+        let mut edges = HashMap::new();
+        for (i, a) in members.iter().enumerate() {
+            for b in members[i + 1..].iter() {
+                if let Some(tier) = self.edges.remove(&edge(*a, *b)) {
+                    edges.insert(edge(*a, *b), tier);
+                }
+            }
+        }
+
+        let mut feats = Vec::with_capacity(members.len());
+        for member in members.iter() {
+            self.parent.remove(member);
+            if let Some(feat) = self.feats.remove(member) {
+                feats.push(feat);
+            }
+        }
+
+        Component {
+            feats: feats,
+            edges: edges,
+        }
+    }
+}
+
+fn edge(a: i64, b: i64) -> (i64, i64) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+///
+/// A connected component of duplicate features
+///
+#[derive(Debug)]
+pub struct Component {
+    pub feats: Vec<Address>,
+
+    /// Tier of each pair of duplicates, keyed by (lowest id, highest id)
+    pub edges: HashMap<(i64, i64), Tier>,
+}
+
+impl Component {
+    ///
+    /// Tier at which two features are duplicates of each other, if they are
+    ///
+    pub fn tier(&self, a: &Address, b: &Address) -> Option<Tier> {
+        self.edges.get(&edge(a.id.unwrap(), b.id.unwrap())).cloned()
+    }
+
+    ///
+    /// Split the component into groups of features that are all duplicates of the
+    /// group's primary feature, taking the feature with the most duplicates first
+    ///
+    pub fn groups(mut self, policy: &SurvivorPolicy) -> Vec<DupGroup> {
+        let mut feats = std::mem::replace(&mut self.feats, Vec::new());
+        feats.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut groups = Vec::new();
+
+        while !feats.is_empty() {
+            let mut primary = 0;
+            let mut primary_dups = 0;
+
+            for (i, feat) in feats.iter().enumerate() {
+                let dups = feats
+                    .iter()
+                    .filter(|dup_feat| self.tier(feat, dup_feat).is_some())
+                    .count();
+
+                if dups > primary_dups {
+                    primary = i;
+                    primary_dups = dups;
+                }
+            }
+
+            let feat = feats.remove(primary);
+
+            let (dup_feats, rest): (Vec<Address>, Vec<Address>) = feats
+                .into_iter()
+                .partition(|dup_feat| self.tier(&feat, dup_feat).is_some());
+            feats = rest;
+
+            groups.push(DupGroup::new(feat, dup_feats, &self.edges, policy));
+        }
+
+        groups
+    }
+}
+
+///
+/// Configuration of the dedupe tiers beyond the exact tier
+///
+#[derive(Debug, Clone)]
+pub struct Tiers {
+    /// When set, the radius in meters used by the fuzzy tier
+    pub fuzzy: Option<f64>,
+
+    /// Has a buildings table been populated
+    pub buildings: bool,
+
+    /// Lowercase ISO 3166-1 country code used to standardize numbers & link names
+    pub country: String,
+}
+
+impl Tiers {
+    ///
+    /// Determine the tier at which a proximal feature is a duplicate of the
+    /// primary feature, returning None if it is not a duplicate
+    ///
+    /// dist: distance in degrees between the features
+    /// within: is the feature within the fuzzy radius
+    /// building: do the features fall within the same building footprint
+    ///
+    pub fn tier(
+        &self,
+        feat: &Address,
+        dup_feat: &Address,
+        dist: f64,
+        within: bool,
+        building: bool,
+    ) -> Option<Tier> {
+        // For the exact tier the dup logic is rather simple & strict
+        // - Must be within 0.00001 degrees (~1m)
+        // - Number must be the same as given - apt numbers included
+        // - Text synonyms must match
+        if dist <= 0.00001 && dup_feat.number == feat.number && dup_feat.names == feat.names {
+            return Some(Tier::Exact);
+        }
+
+        // With fuzzy on, both numbers are standardized before being compared for the
+        // other tiers, numbers that cannot be standardized are only eligible for the exact tier
+        if self.fuzzy.is_some() {
+            match (
+                Address::std_number(&feat.number, &self.country),
+                Address::std_number(&dup_feat.number, &self.country),
+            ) {
+                (Ok(number), Ok(dup_number)) => {
+                    if number != dup_number {
+                        return None;
+                    }
+                }
+                _ => {
+                    return None;
+                }
+            }
+        } else if dup_feat.number != feat.number {
+            return None;
+        }
+
+        // The building tier collapses multiple points for a single building
+        // - Must fall within the same building footprint
+        // - Number must be the same
+        // - Names must be identical or linkable in strict mode
+        if building && (dup_feat.names == feat.names || is_linked(feat, dup_feat, &self.country)) {
+            return Some(Tier::Building);
+        }
+
+        // The fuzzy tier allows for provider differences
+        // - Must be within the given radius in meters
+        // - Standardized number must be the same (10 A => 10a)
+        // - Names must be linkable in strict mode (Main St => Main Street)
+        if self.fuzzy.is_some() && within && is_linked(feat, dup_feat, &self.country) {
+            return Some(Tier::Fuzzy);
+        }
+
+        None
+    }
+}
+
+fn is_linked(feat: &Address, dup_feat: &Address, country: &str) -> bool {
+    let primary = linker::Link::new(feat.id.unwrap(), &feat.names);
+    let potential = vec![linker::Link::new(dup_feat.id.unwrap(), &dup_feat.names)];
+
+    linker::linker(primary, potential, true, country).is_some()
+}
+
+fn dedupe_batch(
     min_id: i64,
     max_id: i64,
+    tiers: &Tiers,
     conn: postgres::Connection,
-    tx: crossbeam::Sender<Address>,
+    tx: crossbeam::Sender<DupLinks>,
 ) {
-    let exact_dups = match pg::Cursor::new(
+    // Fuzzy matches are found using a meter radius
+    let within = match tiers.fuzzy {
+        None => String::from("FALSE"),
+        Some(radius) => pg::dwithin_meters("a.geom", "geom", radius),
+    };
+
+    // Same numbered features within the building footprint of the primary,
+    // matched on the precomputed building id rather than a spatial join per feature
+    let (building, building_match) = match tiers.buildings {
+        false => (String::from("FALSE"), String::from("")),
+        true => (
+            String::from("(a.building IS NOT NULL AND building = a.building)"),
+            String::from(
+                r#"
+                        UNION
+                        SELECT
+                            id
+                        FROM
+                            address
+                        WHERE
+                            building = a.building
+                            AND number = a.number"#,
+            ),
+        ),
+    };
+
+    let dups = match pg::Cursor::new(
         conn,
         format!(
             r#"
@@ -214,12 +942,23 @@ fn exact_batch(
                             'source', source,
                             'output', output,
                             'props', props,
+                            'dist', ST_Distance(a.geom, geom),
+                            'within', {within},
+                            'building', {building},
                             'geom', ST_AsGeoJSON(ST_Force2D(geom))::TEXT
                         ))
                     FROM
                         address
                     WHERE
-                        ST_DWithin(a.geom, geom, 0.00001)
+                        id IN (
+                            SELECT
+                                id
+                            FROM
+                                address
+                            WHERE
+                                ST_DWithin(a.geom, geom, 0.00001)
+                                OR {within}{building_match}
+                        )
                 )
             )
         FROM
@@ -228,6 +967,9 @@ fn exact_batch(
             a.id >= {min_id}
             AND a.id <= {max_id}
     "#,
+            within = within,
+            building = building,
+            building_match = building_match,
             min_id = min_id,
             max_id = max_id
         ),
@@ -236,7 +978,7 @@ fn exact_batch(
         Err(err) => panic!("ERR: {}", err.to_string()),
     };
 
-    for dup_feats in exact_dups {
+    for dup_feats in dups {
         let mut dup_feats = match dup_feats {
             serde_json::value::Value::Object(object) => object,
             _ => panic!("result must be JSON Object"),
@@ -248,68 +990,279 @@ fn exact_batch(
                 Err(err) => panic!("Address Error: {}", err.to_string()),
             };
 
-        let mut dup_feats: Vec<Address> = match dup_feats.remove(&String::from("proximal")).unwrap()
-        {
+        let dups: Vec<(i64, Tier)> = match dup_feats.remove(&String::from("proximal")).unwrap() {
             serde_json::value::Value::Array(feats) => {
-                let mut addrfeats = Vec::with_capacity(feats.len());
+                let mut dups = Vec::with_capacity(feats.len());
 
-                for feat in feats {
-                    addrfeats.push(match Address::from_value(feat) {
-                        Ok(feat) => feat,
+                for dup_feat in feats {
+                    let mut dup_feat = match dup_feat {
+                        serde_json::value::Value::Object(object) => object,
+                        _ => panic!("Duplicate Feature should be JSON Object"),
+                    };
+
+                    let dist = match dup_feat.remove(&String::from("dist")) {
+                        Some(dist) => dist.as_f64().unwrap_or(0.0),
+                        None => 0.0,
+                    };
+                    let within = match dup_feat.remove(&String::from("within")) {
+                        Some(within) => within.as_bool().unwrap_or(false),
+                        None => false,
+                    };
+                    let building = match dup_feat.remove(&String::from("building")) {
+                        Some(building) => building.as_bool().unwrap_or(false),
+                        None => false,
+                    };
+
+                    let dup_feat = match Address::from_value(serde_json::Value::Object(dup_feat)) {
+                        Ok(dup_feat) => dup_feat,
                         Err(err) => panic!("Vec<Address> Error: {}", err.to_string()),
-                    });
+                    };
+
+                    if dup_feat.id == feat.id {
+                        continue;
+                    }
+
+                    // Duplicates are checked in both directions so that both features of a pair
+                    // list each other, a component being complete once every member is listed
+                    let tier = tiers
+                        .tier(&feat, &dup_feat, dist, within, building)
+                        .or_else(|| tiers.tier(&dup_feat, &feat, dist, within, building));
+
+                    if let Some(tier) = tier {
+                        dups.push((dup_feat.id.unwrap(), tier));
+                    }
                 }
 
-                addrfeats
+                dups
             }
             _ => panic!("Duplicate Features should be Vec<Value>"),
         };
 
-        //
-        // For now the dup logic is rather simple & strict
-        // - Number must be the same - apt numbers included
-        // - Text synonyms must match
-        dup_feats = dup_feats
-            .into_iter()
-            .filter(|dup_feat| dup_feat.number == feat.number && dup_feat.names == feat.names)
-            .collect();
+        tx.send(DupLinks {
+            feat: feat,
+            dups: dups,
+        })
+        .unwrap();
+    }
+}
 
-        dup_feats.sort_by(|a, b| {
-            if a.id.unwrap() < b.id.unwrap() {
-                std::cmp::Ordering::Less
-            } else if a.id.unwrap() > b.id.unwrap() {
-                std::cmp::Ordering::Greater
-            } else {
-                std::cmp::Ordering::Equal
-            }
-        });
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Names;
 
-        //
-        // Since this operation is performed in parallel - duplicates could be potentially
-        // processed by multiple threads - resulting in duplicate output. To avoid this
-        // the dup_feat will only be processed if the lowest ID in the match falls within
-        // the min_id/max_id that the given thread is processing
-        //
-        if dup_feats[0].id.unwrap() < feat.id.unwrap()
-            || dup_feats[0].id.unwrap() < min_id
-            || dup_feats[0].id.unwrap() > max_id
-        {
-            continue;
+    fn addr(id: i64) -> Address {
+        Address {
+            id: Some(id),
+            version: 0,
+            number: String::from("10"),
+            names: Names { names: Vec::new() },
+            source: String::from("openaddresses"),
+            output: true,
+            interpolate: true,
+            props: serde_json::Map::new(),
+            geom: vec![-77.4818, 37.5005],
         }
+    }
 
-        if is_hecate {
-            // If it is hecate output - delete all features
-            // but the desired feature
+    fn ids(group: &DupGroup) -> (Option<i64>, Vec<Option<i64>>) {
+        (
+            group.survivor.id,
+            group
+                .duplicates
+                .iter()
+                .map(|(dup_feat, _)| dup_feat.id)
+                .collect(),
+        )
+    }
 
-            for dup_feat in dup_feats {
-                if dup_feat.id.unwrap() != feat.id.unwrap() {
-                    tx.send(dup_feat).unwrap();
-                }
-            }
-        } else {
-            // If not hecate, only print the desired feature
+    #[test]
+    fn test_accuracy_rank() {
+        let accuracy = |accuracy: &str| {
+            let mut feat = addr(1);
+            feat.props.insert(
+                String::from("accuracy"),
+                serde_json::Value::String(String::from(accuracy)),
+            );
+            accuracy_rank(&feat)
+        };
+
+        assert!(accuracy("rooftop") < accuracy("entrance"));
+        assert!(accuracy("entrance") < accuracy("parcel"));
+        assert!(accuracy("parcel") < accuracy("point"));
+        assert!(accuracy("point") < accuracy("interpolated"));
+        assert!(accuracy("interpolated") < accuracy("unknown"));
+        assert_eq!(accuracy_rank(&addr(1)), accuracy("unknown"));
+    }
+
+    #[test]
+    fn test_components() {
+        let policy = SurvivorPolicy {
+            survivor: Survivor::Id,
+            sources: Vec::new(),
+            merge: false,
+            country: String::from("US"),
+        };
+
+        let mut components = Components::new();
+
+        // Features without duplicates are returned as is
+        let component = components
+            .add(DupLinks {
+                feat: addr(9),
+                dups: Vec::new(),
+            })
+            .unwrap();
+        assert_eq!(component.feats.len(), 1);
+
+        // 1 & 2, 2 & 3 and 3 & 4 are duplicates but 1 & 3, 1 & 4 and 2 & 4 are not
+        assert!(components
+            .add(DupLinks {
+                feat: addr(1),
+                dups: vec![(2, Tier::Fuzzy)],
+            })
+            .is_none());
+        assert!(components
+            .add(DupLinks {
+                feat: addr(4),
+                dups: vec![(3, Tier::Fuzzy)],
+            })
+            .is_none());
+        assert!(components
+            .add(DupLinks {
+                feat: addr(3),
+                dups: vec![(2, Tier::Fuzzy), (4, Tier::Fuzzy)],
+            })
+            .is_none());
+
+        let component = components
+            .add(DupLinks {
+                feat: addr(2),
+                dups: vec![(1, Tier::Fuzzy), (3, Tier::Exact)],
+            })
+            .unwrap();
+
+        assert_eq!(component.feats.len(), 4);
+        assert!(components.drain().is_empty());
 
-            tx.send(feat).unwrap();
+        let groups = component.groups(&policy);
+
+        // Every feature is either a survivor or a duplicate of one
+        let mut all: Vec<Option<i64>> = Vec::new();
+        for group in groups.iter() {
+            let (survivor, duplicates) = ids(group);
+            all.push(survivor);
+            all.extend(duplicates);
         }
+        all.sort();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(all, vec![Some(1), Some(2), Some(3), Some(4)]);
+
+        // 1 has the lowest id but is not a duplicate of 3, so cannot survive
+        assert_eq!(ids(&groups[0]), (Some(2), vec![Some(1), Some(3)]));
+        assert_eq!(groups[0].duplicates[1].1, Tier::Exact);
+        assert_eq!(ids(&groups[1]), (Some(4), Vec::new()));
+
+        // 5, 6 & 7 are all duplicates of each other, so any can survive
+        let mut edges = HashMap::new();
+        edges.insert((5, 6), Tier::Fuzzy);
+        edges.insert((5, 7), Tier::Building);
+        edges.insert((6, 7), Tier::Exact);
+
+        let group = DupGroup::new(addr(6), vec![addr(5), addr(7)], &edges, &policy);
+
+        assert_eq!(ids(&group), (Some(5), vec![Some(6), Some(7)]));
+        assert_eq!(group.duplicates[0].1, Tier::Fuzzy);
+        assert_eq!(group.duplicates[1].1, Tier::Building);
+    }
+
+    #[test]
+    fn test_tier() {
+        let tiers = Tiers {
+            fuzzy: Some(50.0),
+            buildings: true,
+            country: String::from("us"),
+        };
+
+        let numbered = |id: i64, number: &str| {
+            let mut feat = addr(id);
+            feat.number = String::from(number);
+            feat
+        };
+
+        // The exact tier compares the numbers as given, whichever feature is the primary
+        assert_eq!(
+            tiers.tier(&numbered(1, "10a"), &numbered(2, "10a"), 0.0, true, true),
+            Some(Tier::Exact)
+        );
+        assert_eq!(
+            tiers.tier(&numbered(1, "10A"), &numbered(2, "10a"), 0.0, true, true),
+            Some(Tier::Building)
+        );
+        assert_eq!(
+            tiers.tier(&numbered(2, "10a"), &numbered(1, "10A"), 0.0, true, true),
+            Some(Tier::Building)
+        );
+        assert_eq!(
+            tiers.tier(&numbered(1, "10 A"), &numbered(2, "10a"), 0.0, true, true),
+            Some(Tier::Building)
+        );
+        assert_eq!(
+            tiers.tier(&numbered(2, "10a"), &numbered(1, "10 A"), 0.0, true, true),
+            Some(Tier::Building)
+        );
+
+        // Numbers that cannot be standardized are only eligible for the exact tier
+        assert_eq!(
+            tiers.tier(
+                &numbered(1, "10-B-C"),
+                &numbered(2, "10-B-C"),
+                0.0,
+                true,
+                true
+            ),
+            Some(Tier::Exact)
+        );
+        assert_eq!(
+            tiers.tier(
+                &numbered(1, "10-B-C"),
+                &numbered(2, "10-b-c"),
+                0.0,
+                true,
+                true
+            ),
+            None
+        );
+        assert_eq!(
+            tiers.tier(
+                &numbered(2, "10-b-c"),
+                &numbered(1, "10-B-C"),
+                0.0,
+                true,
+                true
+            ),
+            None
+        );
+
+        // Without fuzzy, numbers are never standardized
+        let tiers = Tiers {
+            fuzzy: None,
+            buildings: true,
+            country: String::from("us"),
+        };
+
+        assert_eq!(
+            tiers.tier(&numbered(1, "10A"), &numbered(2, "10a"), 0.0, false, true),
+            None
+        );
+        assert_eq!(
+            tiers.tier(&numbered(2, "10a"), &numbered(1, "10A"), 0.0, false, true),
+            None
+        );
+        assert_eq!(
+            tiers.tier(&numbered(1, "10a"), &numbered(2, "10a"), 0.0, false, true),
+            Some(Tier::Exact)
+        );
     }
 }
@@ -4,6 +4,7 @@ extern crate neon;
 extern crate serde_derive;
 #[macro_use]
 extern crate lazy_static;
+extern crate aho_corasick;
 extern crate crossbeam;
 extern crate geo;
 extern crate geojson;
@@ -45,6 +46,7 @@ pub use self::types::Context;
 
 pub use self::types::Name;
 pub use self::types::Names;
+pub use self::types::Policy;
 pub use self::types::Source;
 
 // Functions registered here will be made avaliable to be called from NodeJS
@@ -65,6 +67,7 @@ register_module!(mut m, {
     m.export_function("dedupe_syn", map::dedupe_syn)?;
 
     m.export_function("tokenize_name", text::tokenize_name)?;
+    m.export_function("tokenize_names", text::tokenize_names)?;
 
     m.export_function("classify", classify::classify)?;
     m.export_function("conflate", conflate::conflate)?;
@@ -1,11 +1,11 @@
 use postgres::{Connection, TlsMode};
-use std::collections::HashMap;
-use std::convert::From;
+use std::convert::{From, TryFrom};
 use std::thread;
 
+use crate::types::InputPolicy;
 use crate::util::linker;
 use crate::Context as CrateContext;
-use crate::{Name, Names, Tokens};
+use crate::{Name, Names, Policy, Tokens};
 
 use neon::prelude::*;
 
@@ -132,12 +132,13 @@ pub fn import_addr(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     };
 
     let context = match args.context {
-        Some(context) => CrateContext::from(context),
-        None => CrateContext::new(
-            String::from(""),
-            None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        ),
+        Some(context) => match CrateContext::try_from(context) {
+            Ok(context) => context,
+            Err(err) => {
+                return cx.throw_error(format!("import_addr - invalid context: {}", err));
+            }
+        },
+        None => CrateContext::new(String::from(""), None, Tokens::default()),
     };
 
     let address = pg::Address::new();
@@ -181,12 +182,13 @@ pub fn import_net(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     };
 
     let context = match args.context {
-        Some(context) => CrateContext::from(context),
-        None => CrateContext::new(
-            String::from(""),
-            None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        ),
+        Some(context) => match CrateContext::try_from(context) {
+            Ok(context) => context,
+            Err(err) => {
+                return cx.throw_error(format!("import_net - invalid context: {}", err));
+            }
+        },
+        None => CrateContext::new(String::from(""), None, Tokens::default()),
     };
 
     let network = pg::Network::new();
@@ -239,6 +241,18 @@ pub fn link_addr(mut cx: FunctionContext) -> JsResult<JsBoolean> {
         None => String::from("pt_test"),
     };
 
+    // ISO 3166-1 country code of the addresses, unknown if not given
+    let country = match cx.argument_opt(1) {
+        Some(arg) => {
+            if arg.is_a::<JsUndefined>() || arg.is_a::<JsNull>() {
+                String::from("")
+            } else {
+                arg.downcast::<JsString>().or_throw(&mut cx)?.value()
+            }
+        }
+        None => String::from(""),
+    };
+
     let conn = match Connection::connect(
         format!("postgres://postgres@localhost:5432/{}", &db).as_str(),
         TlsMode::None,
@@ -260,6 +274,7 @@ pub fn link_addr(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
     for cpu in 0..cpus {
         let db_conn = db.clone();
+        let country_n = country.clone();
 
         let strand = match thread::Builder::new()
             .name(format!("Linker #{}", &cpu))
@@ -284,7 +299,7 @@ pub fn link_addr(mut cx: FunctionContext) -> JsResult<JsBoolean> {
 
                 let mut it = min_id;
                 while it < max_id {
-                    link_process(&conn, it, it + 5000);
+                    link_process(&conn, it, it + 5000, &country_n);
                     it += 5001;
                 }
             }) {
@@ -327,7 +342,7 @@ pub struct DbType {
     names: Names,
 }
 
-pub fn link_process(conn: &impl postgres::GenericConnection, min: i64, max: i64) {
+pub fn link_process(conn: &impl postgres::GenericConnection, min: i64, max: i64, country: &str) {
     match conn.query(
         "
         SELECT
@@ -400,7 +415,7 @@ pub fn link_process(conn: &impl postgres::GenericConnection, min: i64, max: i64)
                     .map(|potential| linker::Link::new(potential.id, &potential.names))
                     .collect();
 
-                match linker::linker(primary, potentials, false) {
+                match linker::linker(primary, potentials, false, country) {
                     Some(link_match) => {
                         match trans.execute(
                             &*"
@@ -508,6 +523,50 @@ pub fn dedupe_syn(mut cx: FunctionContext) -> JsResult<JsArray> {
         }
     };
 
+    let input: InputPolicy = match cx.argument_opt(1) {
+        None => InputPolicy::default(),
+        Some(arg) => {
+            if arg.is_a::<JsUndefined>() || arg.is_a::<JsNull>() {
+                InputPolicy::default()
+            } else {
+                let arg_val = cx.argument::<JsValue>(1)?;
+                neon_serde::from_value(&mut cx, arg_val).or_else(|e| {
+                    cx.throw_error(format!("dedupe_syn - unable to assign policy: {:?}", e))
+                })?
+            }
+        }
+    };
+
+    let country = match cx.argument_opt(2) {
+        Some(arg) => {
+            if arg.is_a::<JsUndefined>() || arg.is_a::<JsNull>() {
+                String::from("")
+            } else {
+                arg.downcast::<JsString>().or_throw(&mut cx)?.value()
+            }
+        }
+        None => String::from(""),
+    };
+
+    let region = match cx.argument_opt(3) {
+        Some(arg) => {
+            if arg.is_a::<JsUndefined>() || arg.is_a::<JsNull>() {
+                None
+            } else {
+                let region = arg.downcast::<JsString>().or_throw(&mut cx)?.value();
+
+                match region.is_empty() {
+                    true => None,
+                    false => Some(region.to_uppercase()),
+                }
+            }
+        }
+        None => None,
+    };
+
+    let country = country.to_uppercase();
+    let policy = Policy::resolve(&country, &region, input);
+
     if names.len() == 0 {
         return Ok(cx.empty_array());
     }
@@ -516,9 +575,9 @@ pub fn dedupe_syn(mut cx: FunctionContext) -> JsResult<JsArray> {
     let mut names = Names { names: names };
 
     names.empty();
-    names.sort();
-    names.dedupe();
-    names.filter_outliers();
+    names.sort_with(&policy);
+    names.dedupe(&country);
+    names.filter_outliers_with(&policy);
 
     let display_names: Vec<String> = names.names.into_iter().map(|name| name.display).collect();
 
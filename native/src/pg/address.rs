@@ -23,6 +23,51 @@ impl Address {
             _ => 0,
         }
     }
+
+    ///
+    /// Add the id of the building footprint each address falls within
+    /// as an indexed building column, using a single spatial join
+    ///
+    pub fn buildings(&self, conn: &Connection, buildings: &str) {
+        conn.execute(
+            r#"
+            ALTER TABLE address ADD COLUMN building BIGINT;
+        "#,
+            &[],
+        )
+        .unwrap();
+
+        conn.execute(
+            format!(
+                r#"
+            UPDATE address
+                SET building = b.id
+                FROM {buildings} b
+                WHERE ST_Intersects(b.geom, address.geom);
+        "#,
+                buildings = buildings
+            )
+            .as_str(),
+            &[],
+        )
+        .unwrap();
+
+        conn.execute(
+            r#"
+            CREATE INDEX address_building_idx ON address (building, number);
+        "#,
+            &[],
+        )
+        .unwrap();
+
+        conn.execute(
+            r#"
+            ANALYZE address;
+        "#,
+            &[],
+        )
+        .unwrap();
+    }
 }
 
 impl Table for Address {
@@ -35,6 +35,26 @@ pub trait InputTable {
     fn seq_id(&self, conn: &Connection);
 }
 
+///
+/// Build an SQL condition that is true when the geometry b is within the given
+/// number of meters of the point geometry a
+///
+/// The meter distance is converted to a conservative degree distance at the
+/// latitude of a, so that geometry indexes can be used before the slower
+/// geography comparison
+///
+pub fn dwithin_meters(a: &str, b: &str, meters: f64) -> String {
+    format!(
+        r#"(
+            ST_DWithin({a}, {b}, GREATEST(0.00001, {meters} / (111320 * COS(RADIANS(ST_Y({a}))))))
+            AND ST_DWithin({a}::GEOGRAPHY, {b}::GEOGRAPHY, {meters})
+        )"#,
+        a = a,
+        b = b,
+        meters = meters
+    )
+}
+
 ///
 /// Relatively limited cursor wrapper that will allow a cursor to be
 /// created that returns a single Serde_Json::Value field
@@ -0,0 +1,168 @@
+use super::{Distinct, Stats, StatsAccuracy, StatsBound};
+use std::collections::HashMap;
+
+///
+/// A single metric from two map outputs
+///
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Delta {
+    pub before: i64,
+    pub after: i64,
+    pub delta: i64,
+}
+
+impl Delta {
+    pub fn new(before: i64, after: i64) -> Self {
+        Delta {
+            before: before,
+            after: after,
+            delta: after - before,
+        }
+    }
+}
+
+///
+/// Changes between the stats of a previous & current map output
+///
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StatsDiff {
+    pub feats: Delta,
+    pub clusters: Delta,
+    pub invalid: Delta,
+    pub addresses: Delta,
+    pub intersections: Delta,
+    pub address_orphans: Delta,
+    pub network_orphans: Delta,
+    pub bounds: HashMap<String, StatsBoundDiff>,
+}
+
+impl StatsDiff {
+    pub fn new(before: &Stats, after: &Stats) -> Self {
+        let mut bounds: HashMap<String, StatsBoundDiff> = HashMap::new();
+
+        let empty = StatsBound::new();
+        for name in before.bounds.keys().chain(after.bounds.keys()) {
+            if bounds.contains_key(name) {
+                continue;
+            }
+
+            bounds.insert(
+                name.clone(),
+                StatsBoundDiff::new(
+                    before.bounds.get(name).unwrap_or(&empty),
+                    after.bounds.get(name).unwrap_or(&empty),
+                ),
+            );
+        }
+
+        StatsDiff {
+            feats: Delta::new(before.feats, after.feats),
+            clusters: Delta::new(before.clusters, after.clusters),
+            invalid: Delta::new(before.invalid, after.invalid),
+            addresses: Delta::new(before.addresses, after.addresses),
+            intersections: Delta::new(before.intersections, after.intersections),
+            address_orphans: Delta::new(before.address_orphans, after.address_orphans),
+            network_orphans: Delta::new(before.network_orphans, after.network_orphans),
+            bounds: bounds,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct StatsBoundDiff {
+    pub addresses: Delta,
+    pub intersections: Delta,
+    pub clusters: Delta,
+    pub address_orphans: Delta,
+    pub network_orphans: Delta,
+    pub postcodes: Delta,
+    pub names_added: Vec<String>,
+    pub names_removed: Vec<String>,
+    pub synonyms_added: Vec<String>,
+    pub synonyms_removed: Vec<String>,
+    pub accuracy: AccuracyDiff,
+}
+
+impl StatsBoundDiff {
+    pub fn new(before: &StatsBound, after: &StatsBound) -> Self {
+        let (names_added, names_removed) = added_removed(&before.names, &after.names);
+        let (synonyms_added, synonyms_removed) = added_removed(&before.synonyms, &after.synonyms);
+
+        StatsBoundDiff {
+            addresses: Delta::new(before.addresses, after.addresses),
+            intersections: Delta::new(before.intersections, after.intersections),
+            clusters: Delta::new(before.clusters, after.clusters),
+            address_orphans: Delta::new(before.address_orphans, after.address_orphans),
+            network_orphans: Delta::new(before.network_orphans, after.network_orphans),
+            postcodes: Delta::new(before.custom.postcodes, after.custom.postcodes),
+            names_added: names_added,
+            names_removed: names_removed,
+            synonyms_added: synonyms_added,
+            synonyms_removed: synonyms_removed,
+            accuracy: AccuracyDiff::new(&before.custom.accuracy, &after.custom.accuracy),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AccuracyDiff {
+    pub rooftop: Delta,
+    pub entrance: Delta,
+    pub parcel: Delta,
+    pub point: Delta,
+    pub interpolated: Delta,
+    pub other: Delta,
+}
+
+impl AccuracyDiff {
+    pub fn new(before: &StatsAccuracy, after: &StatsAccuracy) -> Self {
+        AccuracyDiff {
+            rooftop: Delta::new(before.rooftop, after.rooftop),
+            entrance: Delta::new(before.entrance, after.entrance),
+            parcel: Delta::new(before.parcel, after.parcel),
+            point: Delta::new(before.point, after.point),
+            interpolated: Delta::new(before.interpolated, after.interpolated),
+            other: Delta::new(before.other, after.other),
+        }
+    }
+}
+
+///
+/// Return the sorted values only present in after (added)
+/// and those only present in before (removed)
+///
+fn added_removed(before: &Distinct, after: &Distinct) -> (Vec<String>, Vec<String>) {
+    (after.difference(before), before.difference(after))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_added_removed() {
+        assert_eq!(
+            added_removed(
+                &vec![String::from("Main St"), String::from("1st Ave")]
+                    .into_iter()
+                    .collect(),
+                &vec![String::from("Main St"), String::from("2nd Ave")]
+                    .into_iter()
+                    .collect()
+            ),
+            (vec![String::from("2nd Ave")], vec![String::from("1st Ave")])
+        );
+    }
+
+    #[test]
+    fn test_delta() {
+        assert_eq!(
+            Delta::new(10, 7),
+            Delta {
+                before: 10,
+                after: 7,
+                delta: -3
+            }
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+///
+/// Max number of distinct names kept for a single bound, well above the number of
+/// streets of any single county. Names past the cap are not stored so the memory
+/// held per bound stays bounded on large regions, counts & diffs being exact below it
+///
+pub const MAX_DISTINCT: usize = 50_000;
+
+///
+/// Bounded set of distinct names, serialized as a sorted list
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distinct {
+    names: HashSet<String>,
+
+    /// Have names been dropped for exceeding MAX_DISTINCT
+    pub truncated: bool,
+}
+
+impl Distinct {
+    pub fn new() -> Self {
+        Distinct {
+            names: HashSet::new(),
+            truncated: false,
+        }
+    }
+
+    pub fn insert(&mut self, name: &String) {
+        if self.names.contains(name) {
+            return;
+        }
+
+        if self.names.len() >= MAX_DISTINCT {
+            self.truncated = true;
+        } else {
+            self.names.insert(name.clone());
+        }
+    }
+
+    pub fn merge(&mut self, other: Distinct) {
+        self.truncated = self.truncated || other.truncated;
+
+        for name in other.names {
+            if self.names.len() >= MAX_DISTINCT && !self.names.contains(&name) {
+                self.truncated = true;
+            } else {
+                self.names.insert(name);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    ///
+    /// Sorted names not present in the other set
+    ///
+    pub fn difference(&self, other: &Distinct) -> Vec<String> {
+        let mut names: Vec<String> = self.names.difference(&other.names).cloned().collect();
+        names.sort();
+        names
+    }
+
+    fn sorted(&self) -> Vec<&String> {
+        let mut names: Vec<&String> = self.names.iter().collect();
+        names.sort();
+        names
+    }
+}
+
+impl Serialize for Distinct {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.sorted())
+    }
+}
+
+impl<'de> Deserialize<'de> for Distinct {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+impl FromIterator<String> for Distinct {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut distinct = Distinct::new();
+
+        for name in iter {
+            distinct.insert(&name);
+        }
+
+        distinct
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distinct() {
+        let mut distinct = Distinct::new();
+        distinct.insert(&String::from("Main St"));
+        distinct.insert(&String::from("1st Ave"));
+        distinct.insert(&String::from("Main St"));
+
+        assert_eq!(distinct.len(), 2);
+        assert_eq!(
+            serde_json::to_value(&distinct).unwrap(),
+            serde_json::json!(["1st Ave", "Main St"])
+        );
+
+        let mut other = Distinct::new();
+        other.insert(&String::from("Main St"));
+        other.insert(&String::from("2nd Ave"));
+
+        assert_eq!(distinct.difference(&other), vec![String::from("1st Ave")]);
+
+        distinct.merge(other);
+        assert_eq!(distinct.len(), 3);
+        assert_eq!(distinct.truncated, false);
+    }
+
+    #[test]
+    fn test_distinct_cap() {
+        let mut distinct = Distinct::new();
+        for i in 0..MAX_DISTINCT {
+            distinct.insert(&format!("{} St", i));
+        }
+
+        // Names already held are still recognized once the cap is reached
+        distinct.insert(&String::from("0 St"));
+        assert_eq!(distinct.len(), MAX_DISTINCT);
+        assert_eq!(distinct.truncated, false);
+
+        let mut other = Distinct::new();
+        other.insert(&String::from("Main St"));
+
+        distinct.merge(other);
+        assert_eq!(distinct.len(), MAX_DISTINCT);
+        assert_eq!(distinct.truncated, true);
+    }
+}
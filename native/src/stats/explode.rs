@@ -145,6 +145,30 @@ fn get_override(
     }
 }
 
+///
+/// Return the first vertex of the network geometry of a feature
+///
+pub fn first_coord(feat: &geojson::Feature) -> Option<Vec<f64>> {
+    fn first(value: &geojson::Value) -> Option<Vec<f64>> {
+        match value {
+            geojson::Value::LineString(ls) => ls.first().cloned(),
+            geojson::Value::MultiLineString(mls) => match mls.first() {
+                Some(ls) => ls.first().cloned(),
+                None => None,
+            },
+            geojson::Value::GeometryCollection(gc) => {
+                gc.iter().filter_map(|geom| first(&geom.value)).next()
+            }
+            _ => None,
+        }
+    }
+
+    match &feat.geometry {
+        Some(geom) => first(&geom.value),
+        None => None,
+    }
+}
+
 ///
 /// Take a GeoJSON feature and explode it into a vector of individual intersection geometries
 ///
@@ -0,0 +1,168 @@
+use super::{tree, Stats, StatsBound};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+///
+/// Flatten the stats of a single bound into an ordered list of columns,
+/// the names & synonyms lists are reduced to their counts
+///
+fn row(name: &String, bound: &StatsBound) -> Vec<(&'static str, serde_json::Value)> {
+    let accuracy = &bound.custom.accuracy;
+
+    vec![
+        ("name", json!(name)),
+        ("addresses", json!(bound.addresses)),
+        ("intersections", json!(bound.intersections)),
+        ("clusters", json!(bound.clusters)),
+        ("address_orphans", json!(bound.address_orphans)),
+        ("network_orphans", json!(bound.network_orphans)),
+        ("names", json!(bound.names.len())),
+        ("synonyms", json!(bound.synonyms.len())),
+        ("postcodes", json!(bound.custom.postcodes)),
+        (
+            "postcode_coverage",
+            json!(ratio(bound.custom.postcodes, bound.addresses)),
+        ),
+        ("rooftop", json!(accuracy.rooftop)),
+        ("entrance", json!(accuracy.entrance)),
+        ("parcel", json!(accuracy.parcel)),
+        ("point", json!(accuracy.point)),
+        ("interpolated", json!(accuracy.interpolated)),
+        ("other", json!(accuracy.other)),
+        ("length", json!(bound.ranges.length)),
+        ("length_ranged", json!(bound.ranges.length_ranged)),
+        ("addresses_in_range", json!(bound.ranges.addresses_in_range)),
+        (
+            "addresses_out_of_range",
+            json!(bound.ranges.addresses_out_of_range),
+        ),
+        ("addresses_unranged", json!(bound.ranges.addresses_unranged)),
+    ]
+}
+
+///
+/// Write one CSV row per bound, sorted by bound name
+///
+pub fn csv(path: String, stats: &Stats) {
+    let mut output = match File::create(path) {
+        Ok(outfile) => BufWriter::new(outfile),
+        Err(err) => panic!("Unable to write to csv file: {}", err),
+    };
+
+    let header: Vec<&str> = row(&String::new(), &StatsBound::new())
+        .iter()
+        .map(|column| column.0)
+        .collect();
+
+    output
+        .write(format!("{}\n", header.join(",")).as_bytes())
+        .unwrap();
+
+    for name in sorted(&stats.bounds) {
+        let values: Vec<String> = row(name, &stats.bounds[name])
+            .into_iter()
+            .map(|(_, value)| match value {
+                serde_json::Value::String(string) => escape(&string),
+                value => value.to_string(),
+            })
+            .collect();
+
+        output
+            .write(format!("{}\n", values.join(",")).as_bytes())
+            .unwrap();
+    }
+
+    output.flush().unwrap();
+}
+
+///
+/// Write a line delimited GeoJSON Feature per bound, joining
+/// the flattened stats onto the bound geometry
+///
+pub fn geojson(path: String, tree: &rstar::RTree<tree::Rect>, stats: &Stats) {
+    let mut output = match File::create(path) {
+        Ok(outfile) => BufWriter::new(outfile),
+        Err(err) => panic!("Unable to write to geojson file: {}", err),
+    };
+
+    let mut rects: Vec<&tree::Rect> = tree.iter().collect();
+    rects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    for rect in rects {
+        let bound = match stats.bounds.get(&rect.name) {
+            Some(bound) => bound,
+            None => continue,
+        };
+
+        let mut props = serde_json::Map::new();
+        for (key, value) in row(&rect.name, bound) {
+            props.insert(String::from(key), value);
+        }
+
+        let feat = geojson::Feature {
+            id: None,
+            bbox: None,
+            geometry: Some(geojson::Geometry::new(geojson::Value::from(&rect.geom))),
+            properties: Some(props),
+            foreign_members: None,
+        };
+
+        output
+            .write(format!("{}\n", geojson::GeoJson::from(feat).to_string()).as_bytes())
+            .unwrap();
+    }
+
+    output.flush().unwrap();
+}
+
+fn sorted(bounds: &HashMap<String, StatsBound>) -> Vec<&String> {
+    let mut names: Vec<&String> = bounds.keys().collect();
+    names.sort();
+    names
+}
+
+fn ratio(part: i64, total: i64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        (part as f64 / total as f64 * 10000.0).round() / 10000.0
+    }
+}
+
+///
+/// Quote a CSV value if it contains a delimiter, quote or newline
+///
+fn escape(value: &String) -> String {
+    if value.contains(|c: char| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", value.replace("\"", "\"\""))
+    } else {
+        value.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape(&String::from("11001")), String::from("11001"));
+        assert_eq!(
+            escape(&String::from("Washington, DC")),
+            String::from("\"Washington, DC\"")
+        );
+        assert_eq!(
+            escape(&String::from("The \"District\"")),
+            String::from("\"The \"\"District\"\"\"")
+        );
+    }
+
+    #[test]
+    fn test_ratio() {
+        assert_eq!(ratio(0, 0), 0.0);
+        assert_eq!(ratio(1, 2), 0.5);
+        assert_eq!(ratio(1, 3), 0.3333);
+    }
+}
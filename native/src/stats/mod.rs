@@ -1,16 +1,29 @@
 use super::stream::GeoStream;
 use geo::algorithm::contains::Contains;
 use neon::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 mod count;
+mod diff;
+mod distinct;
 mod explode;
+mod export;
+mod range;
 mod tree;
 
+use distinct::Distinct;
+use range::StatsRanges;
+
+// Number of input lines sent to a stats thread at once
+const CHUNK: usize = 1000;
+
 #[derive(Serialize, Deserialize, Debug)]
 struct StatsArgs {
     input: Option<String>,
     bounds: Option<String>,
+    previous: Option<String>,
+    csv: Option<String>,
+    geojson: Option<String>,
 }
 
 impl StatsArgs {
@@ -18,6 +31,9 @@ impl StatsArgs {
         StatsArgs {
             input: None,
             bounds: None,
+            previous: None,
+            csv: None,
+            geojson: None,
         }
     }
 }
@@ -31,6 +47,7 @@ pub struct Stats {
     pub intersections: i64,   // Total number of address features
     pub address_orphans: i64, // Total number of address orphans
     pub network_orphans: i64, // Total number of network orphans
+    pub ranges: StatsRanges,  // Interpolation coverage & range quality
     pub bounds: HashMap<String, StatsBound>,
 }
 
@@ -44,33 +61,77 @@ impl Stats {
             intersections: 0,
             address_orphans: 0,
             network_orphans: 0,
+            ranges: StatsRanges::new(),
             bounds: HashMap::new(),
         }
     }
+
+    ///
+    /// Add the counts of stats computed over another part of the same input
+    ///
+    fn merge(&mut self, other: Stats) {
+        self.feats = self.feats + other.feats;
+        self.clusters = self.clusters + other.clusters;
+        self.invalid = self.invalid + other.invalid;
+        self.addresses = self.addresses + other.addresses;
+        self.intersections = self.intersections + other.intersections;
+        self.address_orphans = self.address_orphans + other.address_orphans;
+        self.network_orphans = self.network_orphans + other.network_orphans;
+        self.ranges.merge(&other.ranges);
+
+        for (name, bound) in other.bounds {
+            match self.bounds.get_mut(&name) {
+                Some(existing) => existing.merge(bound),
+                None => {
+                    self.bounds.insert(name, bound);
+                }
+            };
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StatsBound {
-    pub names: Vec<String>,
-    pub synonyms: Vec<String>,
+    pub names: Distinct,    // Distinct primary names, up to distinct::MAX_DISTINCT
+    pub synonyms: Distinct, // Distinct synonyms, up to distinct::MAX_DISTINCT
     pub addresses: i64,
     pub intersections: i64,
+    pub clusters: i64, // Features with addresses and a network within the bound
+    pub address_orphans: i64, // Address orphans with addresses within the bound
+    pub network_orphans: i64, // Network orphans starting within the bound
+    pub ranges: StatsRanges, // Segments starting & addresses within the bound
     pub custom: StatsCustom,
 }
 
 impl StatsBound {
     fn new() -> Self {
         StatsBound {
-            names: Vec::new(),
-            synonyms: Vec::new(),
+            names: Distinct::new(),
+            synonyms: Distinct::new(),
             addresses: 0,
             intersections: 0,
+            clusters: 0,
+            address_orphans: 0,
+            network_orphans: 0,
+            ranges: StatsRanges::new(),
             custom: StatsCustom::new(),
         }
     }
+
+    fn merge(&mut self, other: StatsBound) {
+        self.names.merge(other.names);
+        self.synonyms.merge(other.synonyms);
+        self.addresses = self.addresses + other.addresses;
+        self.intersections = self.intersections + other.intersections;
+        self.clusters = self.clusters + other.clusters;
+        self.address_orphans = self.address_orphans + other.address_orphans;
+        self.network_orphans = self.network_orphans + other.network_orphans;
+        self.ranges.merge(&other.ranges);
+        self.custom.merge(&other.custom);
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StatsCustom {
     pub postcodes: i64,
     pub accuracy: StatsAccuracy,
@@ -83,23 +144,54 @@ impl StatsCustom {
             accuracy: StatsAccuracy::new(),
         }
     }
+
+    pub fn merge(&mut self, other: &StatsCustom) {
+        self.postcodes = self.postcodes + other.postcodes;
+        self.accuracy.merge(&other.accuracy);
+    }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct StatsAccuracy {
     pub rooftop: i64,
+    pub entrance: i64,
     pub parcel: i64,
     pub point: i64,
+    pub interpolated: i64,
+    pub other: i64, // Unrecognized accuracy values
 }
 
 impl StatsAccuracy {
     pub fn new() -> Self {
         StatsAccuracy {
             rooftop: 0,
+            entrance: 0,
             parcel: 0,
             point: 0,
+            interpolated: 0,
+            other: 0,
         }
     }
+
+    pub fn count(&mut self, accuracy: &str) {
+        match accuracy {
+            "rooftop" => self.rooftop = self.rooftop + 1,
+            "entrance" => self.entrance = self.entrance + 1,
+            "parcel" => self.parcel = self.parcel + 1,
+            "point" => self.point = self.point + 1,
+            "interpolated" => self.interpolated = self.interpolated + 1,
+            _ => self.other = self.other + 1,
+        };
+    }
+
+    pub fn merge(&mut self, other: &StatsAccuracy) {
+        self.rooftop = self.rooftop + other.rooftop;
+        self.entrance = self.entrance + other.entrance;
+        self.parcel = self.parcel + other.parcel;
+        self.point = self.point + other.point;
+        self.interpolated = self.interpolated + other.interpolated;
+        self.other = self.other + other.other;
+    }
 }
 
 pub fn stats(mut cx: FunctionContext) -> JsResult<JsValue> {
@@ -129,138 +221,323 @@ pub fn stats(mut cx: FunctionContext) -> JsResult<JsValue> {
         false => rstar::RTree::bulk_load(vec![]),
     };
 
-    let mut stats = Stats::new();
+    match args.previous {
+        None => {
+            let stats = process(args.input, &tree, boundmap);
 
-    for geo in GeoStream::new(args.input) {
-        let feat = match geo {
-            geojson::GeoJson::Feature(feat) => feat,
-            _ => {
-                stats.invalid = stats.invalid + 1;
-                continue;
-            }
-        };
+            export(args.csv, args.geojson, &tree, &stats);
 
-        if feat.geometry.is_none() {
-            stats.invalid = stats.invalid + 1;
-            continue;
+            Ok(neon_serde::to_value(&mut cx, &stats)
+                .or_else(|e| cx.throw_error(format!("stats: {:?}", e)))?)
         }
+        Some(previous) => {
+            let before = process(Some(previous), &tree, boundmap.clone());
+            let after = process(args.input, &tree, boundmap);
 
-        match feat.geometry.as_ref().unwrap().value {
-            geojson::Value::MultiPoint(_) | geojson::Value::GeometryCollection(_) => {
-                stats.feats = stats.feats + 1;
+            export(args.csv, args.geojson, &tree, &after);
+
+            let diff = diff::StatsDiff::new(&before, &after);
+
+            Ok(neon_serde::to_value(&mut cx, &diff)
+                .or_else(|e| cx.throw_error(format!("stats: {:?}", e)))?)
+        }
+    }
+}
+
+///
+/// Write the optional per-bound CSV & GeoJSON exports
+///
+fn export(
+    csv: Option<String>,
+    geojson: Option<String>,
+    tree: &rstar::RTree<tree::Rect>,
+    stats: &Stats,
+) {
+    if let Some(csv) = csv {
+        export::csv(csv, stats);
+        println!("ok - wrote csv export");
+    }
+
+    if let Some(geojson) = geojson {
+        export::geojson(geojson, tree, stats);
+        println!("ok - wrote geojson export");
+    }
+}
+
+///
+/// Stream a given map output file, returning the computed stats.
+/// The boundmap must contain an empty StatsBound for each bound in the tree
+///
+/// Lines are parsed & counted in chunks across all cpus, each chunk producing
+/// partial Stats holding only the bounds it touched. Partials are merged into
+/// a single Stats as they arrive so the bounds are only held in memory once
+///
+fn process(
+    input: Option<String>,
+    tree: &rstar::RTree<tree::Rect>,
+    boundmap: HashMap<String, StatsBound>,
+) -> Stats {
+    let cpus = num_cpus::get();
+    let is_bounded = boundmap.len() > 0;
+
+    // Bound the number of chunks & partials held in memory at any one time
+    let (tx, rx) = crossbeam::channel::bounded::<Vec<String>>(cpus * 2);
+    let (partial_tx, partial_rx) = crossbeam::channel::bounded::<Stats>(cpus * 2);
+
+    crossbeam::scope(|scope| {
+        let merger = scope.spawn(move |_| {
+            let mut stats = Stats::new();
+            stats.bounds = boundmap;
+
+            for partial in partial_rx.iter() {
+                stats.merge(partial);
             }
-            _ => {
-                stats.invalid = stats.invalid + 1;
-                continue;
+
+            stats
+        });
+
+        let mut web = Vec::new();
+
+        for cpu in 0..cpus {
+            let rx_n = rx.clone();
+            let partial_tx_n = partial_tx.clone();
+
+            let strand = match scope
+                .builder()
+                .name(format!("Stats #{}", &cpu))
+                .spawn(move |_| {
+                    for chunk in rx_n.iter() {
+                        let mut partial = Stats::new();
+
+                        for line in chunk {
+                            feature(GeoStream::parse(line), tree, is_bounded, &mut partial);
+                        }
+
+                        partial_tx_n.send(partial).unwrap();
+                    }
+                }) {
+                Ok(strand) => strand,
+                Err(err) => panic!("Thread Creation Error: {}", err.to_string()),
+            };
+
+            web.push(strand);
+        }
+
+        drop(rx);
+        drop(partial_tx);
+
+        let mut stream = GeoStream::new(input);
+        let mut chunk = Vec::with_capacity(CHUNK);
+
+        while let Some(line) = stream.next_line() {
+            chunk.push(line);
+
+            if chunk.len() >= CHUNK {
+                tx.send(chunk).unwrap();
+                chunk = Vec::with_capacity(CHUNK);
             }
-        };
+        }
+
+        if chunk.len() > 0 {
+            tx.send(chunk).unwrap();
+        }
+
+        drop(tx);
+
+        for strand in web {
+            strand.join().unwrap();
+        }
 
-        let addr = count::addresses(&feat);
-        let intsec = count::intersections(&feat);
-        let net = count::networks(&feat);
+        merger.join().unwrap()
+    })
+    .unwrap()
+}
+
+///
+/// Add the counts of a single map output feature to the stats & any bounds it falls within
+///
+fn feature(
+    geo: geojson::GeoJson,
+    tree: &rstar::RTree<tree::Rect>,
+    is_bounded: bool,
+    stats: &mut Stats,
+) {
+    let feat = match geo {
+        geojson::GeoJson::Feature(feat) => feat,
+        _ => {
+            stats.invalid = stats.invalid + 1;
+            return;
+        }
+    };
 
-        stats.addresses = stats.addresses + addr;
-        stats.intersections = stats.intersections + intsec;
+    if feat.geometry.is_none() {
+        stats.invalid = stats.invalid + 1;
+        return;
+    }
 
-        if addr == 0 && net == 0 && intsec == 0 {
+    match feat.geometry.as_ref().unwrap().value {
+        geojson::Value::MultiPoint(_) | geojson::Value::GeometryCollection(_) => {
+            stats.feats = stats.feats + 1;
+        }
+        _ => {
             stats.invalid = stats.invalid + 1;
-        } else if addr > 0 && net > 0 {
-            stats.clusters = stats.clusters + 1;
-        } else if addr > 0 {
-            stats.address_orphans = stats.address_orphans + 1;
-        } else if net > 0 {
-            stats.network_orphans = stats.network_orphans + 1;
+            return;
+        }
+    };
+
+    let addr = count::addresses(&feat);
+    let intsec = count::intersections(&feat);
+    let net = count::networks(&feat);
+
+    stats.addresses = stats.addresses + addr;
+    stats.intersections = stats.intersections + intsec;
+
+    if addr == 0 && net == 0 && intsec == 0 {
+        stats.invalid = stats.invalid + 1;
+    } else if addr > 0 && net > 0 {
+        stats.clusters = stats.clusters + 1;
+    } else if addr > 0 {
+        stats.address_orphans = stats.address_orphans + 1;
+    } else if net > 0 {
+        stats.network_orphans = stats.network_orphans + 1;
+    }
+
+    let segments = range::segments(&feat);
+    let overlaps = range::overlaps(&segments);
+
+    for segment in segments.iter() {
+        stats.ranges.segment(segment);
+    }
+    stats.ranges.overlaps = stats.ranges.overlaps + overlaps;
+
+    // Is each address within the range of its nearest segment, the inner
+    // None being an address whose nearest segment has no range at all
+    let addr_in_range = |addr: &explode::StatAddress| -> Option<Option<bool>> {
+        match (
+            range::nearest(&segments, &addr.geom),
+            range::number(&addr.number),
+        ) {
+            (Some(segment), Some(number)) => Some(segment.contains(number)),
+            _ => None,
+        }
+    };
+
+    if !is_bounded {
+        for addr in explode::addresses(&feat) {
+            if let Some(in_range) = addr_in_range(&addr) {
+                stats.ranges.address(in_range);
+            }
         }
+    } else {
+        for addr in explode::addresses(&feat) {
+            if let Some(in_range) = addr_in_range(&addr) {
+                stats.ranges.address(in_range);
 
-        if is_bounded {
-            let names: Vec<String> = match &feat.properties {
+                for name in within(tree, &addr.geom) {
+                    stats_bound(stats, &name).ranges.address(in_range);
+                }
+            }
+        }
+
+        for segment in segments.iter() {
+            if let Some(coord) = segment.geom.first() {
+                for name in within(tree, coord) {
+                    stats_bound(stats, &name).ranges.segment(segment);
+                }
+            }
+        }
+
+        let names: Vec<String> = match &feat.properties {
+            None => vec![],
+            Some(ref props) => match props.get(&String::from("carmen:text")) {
                 None => vec![],
-                Some(ref props) => match props.get(&String::from("carmen:text")) {
-                    None => vec![],
-                    Some(ref names) => match names {
-                        serde_json::Value::String(string) => {
-                            string.split(",").map(|name| String::from(name)).collect()
-                        }
-                        _ => vec![],
-                    },
+                Some(ref names) => match names {
+                    serde_json::Value::String(string) => {
+                        string.split(",").map(|name| String::from(name)).collect()
+                    }
+                    _ => vec![],
                 },
-            };
+            },
+        };
 
-            for addr in explode::addresses(&feat) {
-                for bound in tree.locate_all_at_point(&[addr.geom[0], addr.geom[1]]) {
-                    if bound
-                        .geom
-                        .contains(&geo::Point::new(addr.geom[0], addr.geom[1]))
-                    {
-                        let mut bm_item = boundmap.get_mut(&bound.name).unwrap();
-
-                        bm_item.addresses = bm_item.addresses + 1;
-
-                        if names.len() > 0 {
-                            if !bm_item.names.contains(&names[0]) {
-                                bm_item.names.push(names[0].clone());
-                            }
-
-                            if names.len() > 1 {
-                                for ele in 1..names.len() {
-                                    if !bm_item.synonyms.contains(&names[ele]) {
-                                        bm_item.synonyms.push(names[ele].clone());
-                                    }
-                                }
-                            }
-                        }
+        // Bounds the feature falls within, used to count clusters & orphans
+        let mut feat_bounds: HashSet<String> = HashSet::new();
 
-                        if addr.postcode.is_some() {
-                            bm_item.custom.postcodes = bm_item.custom.postcodes + 1;
-                        }
+        for addr in explode::addresses(&feat) {
+            for name in within(tree, &addr.geom) {
+                let bm_item = stats_bound(stats, &name);
+
+                bm_item.addresses = bm_item.addresses + 1;
 
-                        match &addr.accuracy {
-                            Some(accuracy) => {
-                                if accuracy == &String::from("rooftop") {
-                                    bm_item.custom.accuracy.rooftop =
-                                        bm_item.custom.accuracy.rooftop + 1;
-                                } else if accuracy == &String::from("point") {
-                                    bm_item.custom.accuracy.point =
-                                        bm_item.custom.accuracy.point + 1;
-                                } else if accuracy == &String::from("parcel") {
-                                    bm_item.custom.accuracy.parcel =
-                                        bm_item.custom.accuracy.parcel + 1;
-                                } else {
-                                    panic!(
-                                        "accuracy must be rooftop/parcel/point not {}",
-                                        accuracy
-                                    );
-                                }
-                            }
-                            None => (),
-                        };
+                if names.len() > 0 {
+                    bm_item.names.insert(&names[0]);
+
+                    for synonym in names.iter().skip(1) {
+                        bm_item.synonyms.insert(synonym);
                     }
                 }
+
+                if addr.postcode.is_some() {
+                    bm_item.custom.postcodes = bm_item.custom.postcodes + 1;
+                }
+
+                match &addr.accuracy {
+                    Some(accuracy) => bm_item.custom.accuracy.count(accuracy),
+                    None => (),
+                };
+
+                feat_bounds.insert(name);
             }
+        }
 
-            for intersection in explode::intersections(&feat) {
-                for bound in tree.locate_all_at_point(&[intersection.geom[0], intersection.geom[1]])
-                {
-                    if bound
-                        .geom
-                        .contains(&geo::Point::new(intersection.geom[0], intersection.geom[1]))
-                    {
-                        let mut bm_item = boundmap.get_mut(&bound.name).unwrap();
+        for intersection in explode::intersections(&feat) {
+            for name in within(tree, &intersection.geom) {
+                let bm_item = stats_bound(stats, &name);
 
-                        bm_item.intersections = bm_item.intersections + 1;
-                    }
-                }
+                bm_item.intersections = bm_item.intersections + 1;
             }
         }
-    }
 
-    for value in boundmap.values_mut() {
-        value.names.sort();
-        value.synonyms.sort();
+        // Network orphans have no addresses and are located by their first vertex
+        if addr == 0 && net > 0 {
+            match explode::first_coord(&feat) {
+                Some(coord) => feat_bounds.extend(within(tree, &coord)),
+                None => (),
+            };
+        }
+
+        for name in feat_bounds {
+            let bm_item = stats_bound(stats, &name);
+
+            bm_item.ranges.overlaps = bm_item.ranges.overlaps + overlaps;
+
+            if addr > 0 && net > 0 {
+                bm_item.clusters = bm_item.clusters + 1;
+            } else if addr > 0 {
+                bm_item.address_orphans = bm_item.address_orphans + 1;
+            } else if net > 0 {
+                bm_item.network_orphans = bm_item.network_orphans + 1;
+            }
+        }
     }
+}
 
-    stats.bounds = boundmap;
+///
+/// Stats of the bound of a given name, created on first use
+///
+fn stats_bound<'a>(stats: &'a mut Stats, name: &String) -> &'a mut StatsBound {
+    stats
+        .bounds
+        .entry(name.clone())
+        .or_insert_with(StatsBound::new)
+}
 
-    Ok(neon_serde::to_value(&mut cx, &stats)
-        .or_else(|e| cx.throw_error(format!("stats: {:?}", e)))?)
+///
+/// Names of all bounds containing the given coordinate
+///
+fn within(tree: &rstar::RTree<tree::Rect>, coord: &Vec<f64>) -> Vec<String> {
+    tree.locate_all_at_point(&[coord[0], coord[1]])
+        .filter(|bound| bound.geom.contains(&geo::Point::new(coord[0], coord[1])))
+        .map(|bound| bound.name.clone())
+        .collect()
 }
@@ -0,0 +1,381 @@
+use geo::algorithm::haversine_distance::HaversineDistance;
+
+///
+/// Interpolation coverage & range quality metrics
+///
+/// The share of addresses within the range of their segment
+/// is addresses_in_range / (addresses_in_range + addresses_out_of_range), addresses
+/// nearest to a segment without any range are counted apart as addresses_unranged
+///
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StatsRanges {
+    pub length: i64,                 // Network length (m)
+    pub length_ranged: i64,          // Network length (m) with a carmen:lfromhn/rfromhn range
+    pub length_unranged: i64,        // Network length (m) without a range
+    pub parity_consistent: i64,      // Ranged sides with from/to numbers matching the parity
+    pub parity_inconsistent: i64,    // Ranged sides with from/to numbers not matching the parity
+    pub overlaps: i64,               // Pairs of sides within a cluster with overlapping ranges
+    pub addresses_in_range: i64,     // Cluster addresses within the range of the nearest segment
+    pub addresses_out_of_range: i64, // Cluster addresses outside the range of the nearest segment
+    pub addresses_unranged: i64,     // Cluster addresses whose nearest segment has no range
+}
+
+impl StatsRanges {
+    pub fn new() -> Self {
+        StatsRanges {
+            length: 0,
+            length_ranged: 0,
+            length_unranged: 0,
+            parity_consistent: 0,
+            parity_inconsistent: 0,
+            overlaps: 0,
+            addresses_in_range: 0,
+            addresses_out_of_range: 0,
+            addresses_unranged: 0,
+        }
+    }
+
+    ///
+    /// Add the length & parity metrics of a single segment
+    ///
+    pub fn segment(&mut self, segment: &StatSegment) {
+        self.length = self.length + segment.length;
+
+        if segment.left.is_some() || segment.right.is_some() {
+            self.length_ranged = self.length_ranged + segment.length;
+        } else {
+            self.length_unranged = self.length_unranged + segment.length;
+        }
+
+        for side in segment.sides() {
+            if side.is_consistent() {
+                self.parity_consistent = self.parity_consistent + 1;
+            } else {
+                self.parity_inconsistent = self.parity_inconsistent + 1;
+            }
+        }
+    }
+
+    pub fn merge(&mut self, other: &StatsRanges) {
+        self.length = self.length + other.length;
+        self.length_ranged = self.length_ranged + other.length_ranged;
+        self.length_unranged = self.length_unranged + other.length_unranged;
+        self.parity_consistent = self.parity_consistent + other.parity_consistent;
+        self.parity_inconsistent = self.parity_inconsistent + other.parity_inconsistent;
+        self.overlaps = self.overlaps + other.overlaps;
+        self.addresses_in_range = self.addresses_in_range + other.addresses_in_range;
+        self.addresses_out_of_range = self.addresses_out_of_range + other.addresses_out_of_range;
+        self.addresses_unranged = self.addresses_unranged + other.addresses_unranged;
+    }
+
+    ///
+    /// Add whether an address falls within the range of its nearest segment,
+    /// None if the nearest segment has no range to fall within
+    ///
+    pub fn address(&mut self, in_range: Option<bool>) {
+        match in_range {
+            Some(true) => self.addresses_in_range = self.addresses_in_range + 1,
+            Some(false) => self.addresses_out_of_range = self.addresses_out_of_range + 1,
+            None => self.addresses_unranged = self.addresses_unranged + 1,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StatRange {
+    pub from: i64,
+    pub to: i64,
+    pub parity: Option<String>,
+}
+
+impl StatRange {
+    fn min(&self) -> i64 {
+        std::cmp::min(self.from, self.to)
+    }
+
+    fn max(&self) -> i64 {
+        std::cmp::max(self.from, self.to)
+    }
+
+    ///
+    /// Do the from/to numbers of the range match the parity
+    ///
+    pub fn is_consistent(&self) -> bool {
+        match self.parity.as_ref().map(|parity| parity.as_str()) {
+            Some("O") => self.from % 2 != 0 && self.to % 2 != 0,
+            Some("E") => self.from % 2 == 0 && self.to % 2 == 0,
+            Some("B") => true,
+            _ => false,
+        }
+    }
+
+    fn parity_match(&self, number: i64) -> bool {
+        match self.parity.as_ref().map(|parity| parity.as_str()) {
+            Some("O") => number % 2 != 0,
+            Some("E") => number % 2 == 0,
+            _ => true,
+        }
+    }
+
+    ///
+    /// Does the range contain the given address number
+    ///
+    pub fn contains(&self, number: i64) -> bool {
+        number >= self.min() && number <= self.max() && self.parity_match(number)
+    }
+
+    ///
+    /// Do two ranges share any potential address numbers
+    ///
+    pub fn overlaps(&self, other: &StatRange) -> bool {
+        if self.min() > other.max() || other.min() > self.max() {
+            return false;
+        }
+
+        match (
+            self.parity.as_ref().map(|parity| parity.as_str()),
+            other.parity.as_ref().map(|parity| parity.as_str()),
+        ) {
+            (Some("O"), Some("E")) | (Some("E"), Some("O")) => false,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StatSegment {
+    pub geom: Vec<Vec<f64>>,
+    pub length: i64,
+    pub left: Option<StatRange>,
+    pub right: Option<StatRange>,
+}
+
+impl StatSegment {
+    pub fn sides(&self) -> Vec<&StatRange> {
+        let mut sides = Vec::with_capacity(2);
+
+        if let Some(ref left) = self.left {
+            sides.push(left);
+        }
+        if let Some(ref right) = self.right {
+            sides.push(right);
+        }
+
+        sides
+    }
+
+    ///
+    /// Whether either side's range contains the number, None if neither side has a range
+    ///
+    pub fn contains(&self, number: i64) -> Option<bool> {
+        let sides = self.sides();
+
+        if sides.is_empty() {
+            return None;
+        }
+
+        Some(sides.iter().any(|side| side.contains(number)))
+    }
+
+    ///
+    /// Planar squared distance from the segment to a given point, only
+    /// used to find the nearest segment within a single cluster
+    ///
+    pub fn distance_2(&self, point: &Vec<f64>) -> f64 {
+        let mut min = std::f64::MAX;
+
+        for pair in self.geom.windows(2) {
+            let (ax, ay) = (pair[0][0], pair[0][1]);
+            let (bx, by) = (pair[1][0], pair[1][1]);
+            let (dx, dy) = (bx - ax, by - ay);
+
+            let len_2 = dx * dx + dy * dy;
+            let t = if len_2 == 0.0 {
+                0.0
+            } else {
+                (((point[0] - ax) * dx + (point[1] - ay) * dy) / len_2)
+                    .max(0.0)
+                    .min(1.0)
+            };
+
+            let (px, py) = (ax + t * dx - point[0], ay + t * dy - point[1]);
+            let dist = px * px + py * py;
+
+            if dist < min {
+                min = dist;
+            }
+        }
+
+        min
+    }
+}
+
+///
+/// Take a GeoJSON feature and explode it into a vector of individual network segments
+/// along with their interpolation ranges
+///
+pub fn segments(feat: &geojson::Feature) -> Vec<StatSegment> {
+    let mut segs = Vec::new();
+
+    let (lines, ele) = match &feat.geometry {
+        Some(geom) => match &geom.value {
+            geojson::Value::GeometryCollection(gc) => {
+                match gc
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(ele, geom)| match &geom.value {
+                        geojson::Value::MultiLineString(mls) => Some((mls, ele)),
+                        _ => None,
+                    })
+                    .next()
+                {
+                    Some(network) => network,
+                    None => {
+                        return segs;
+                    }
+                }
+            }
+            _ => {
+                return segs;
+            }
+        },
+        None => {
+            return segs;
+        }
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        let mut length = 0.0;
+        for pair in line.windows(2) {
+            length = length
+                + geo::Point::new(pair[0][0], pair[0][1])
+                    .haversine_distance(&geo::Point::new(pair[1][0], pair[1][1]));
+        }
+
+        segs.push(StatSegment {
+            geom: line.clone(),
+            length: length.round() as i64,
+            left: get_range(
+                feat,
+                "carmen:lfromhn",
+                "carmen:ltohn",
+                "carmen:parityl",
+                ele,
+                i,
+            ),
+            right: get_range(
+                feat,
+                "carmen:rfromhn",
+                "carmen:rtohn",
+                "carmen:parityr",
+                ele,
+                i,
+            ),
+        });
+    }
+
+    segs
+}
+
+///
+/// Count the pairs of segment sides with overlapping ranges,
+/// the left & right side of a single segment are not compared
+///
+pub fn overlaps(segments: &Vec<StatSegment>) -> i64 {
+    let mut overlaps = 0;
+
+    for a in 0..segments.len() {
+        for b in a + 1..segments.len() {
+            for a_side in segments[a].sides() {
+                for b_side in segments[b].sides() {
+                    if a_side.overlaps(b_side) {
+                        overlaps = overlaps + 1;
+                    }
+                }
+            }
+        }
+    }
+
+    overlaps
+}
+
+///
+/// Return the segment nearest to a given point
+///
+pub fn nearest<'a>(segments: &'a Vec<StatSegment>, point: &Vec<f64>) -> Option<&'a StatSegment> {
+    let mut nearest: Option<(&StatSegment, f64)> = None;
+
+    for segment in segments.iter() {
+        let dist = segment.distance_2(point);
+
+        match nearest {
+            Some((_, min)) if min <= dist => (),
+            _ => nearest = Some((segment, dist)),
+        };
+    }
+
+    nearest.map(|(segment, _)| segment)
+}
+
+///
+/// Parse the leading numeric portion of an address number (200a => 200)
+///
+pub fn number(number: &String) -> Option<i64> {
+    let digits: String = number.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+    digits.parse::<i64>().ok()
+}
+
+fn get_range(
+    feat: &geojson::Feature,
+    from: &str,
+    to: &str,
+    parity: &str,
+    ele: usize,
+    line: usize,
+) -> Option<StatRange> {
+    let from = get_value(feat, from, ele, line).and_then(|value| as_number(&value));
+    let to = get_value(feat, to, ele, line).and_then(|value| as_number(&value));
+    let parity = match get_value(feat, parity, ele, line) {
+        Some(serde_json::Value::String(parity)) => Some(parity),
+        _ => None,
+    };
+
+    match (from, to) {
+        (Some(from), Some(to)) => Some(StatRange {
+            from: from,
+            to: to,
+            parity: parity,
+        }),
+        _ => None,
+    }
+}
+
+fn get_value(
+    feat: &geojson::Feature,
+    key: &str,
+    ele: usize,
+    line: usize,
+) -> Option<serde_json::Value> {
+    match feat.properties {
+        None => None,
+        Some(ref props) => match props.get(key) {
+            Some(serde_json::Value::Array(array)) => match array.get(ele) {
+                Some(serde_json::Value::Array(array)) => match array.get(line) {
+                    Some(value) if !value.is_null() => Some(value.clone()),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        },
+    }
+}
+
+fn as_number(value: &serde_json::Value) -> Option<i64> {
+    match value {
+        serde_json::Value::Number(num) => num.as_i64(),
+        serde_json::Value::String(string) => number(string),
+        _ => None,
+    }
+}
@@ -51,10 +51,11 @@ impl GeoStream {
     }
 }
 
-impl Iterator for GeoStream {
-    type Item = geojson::GeoJson;
-
-    fn next(&mut self) -> Option<Self::Item> {
+impl GeoStream {
+    ///
+    /// Return the next non-empty line of the input, without parsing it
+    ///
+    pub fn next_line(&mut self) -> Option<String> {
         let mut line = Some(String::from(""));
 
         while line.is_some() && line.as_ref().unwrap().trim().len() == 0 {
@@ -64,25 +65,36 @@ impl Iterator for GeoStream {
             };
         }
 
-        match line {
-            None => {
-                return None;
-            }
-            Some(mut line) => {
-                //Remove Ascii Record Separators at beginning or end of line
-                if line.ends_with("\u{001E}") {
-                    line.pop();
-                } else if line.starts_with("\u{001E}") {
-                    line.replace_range(0..1, "");
-                }
+        line
+    }
 
-                match line.parse::<geojson::GeoJson>() {
-                    Ok(geojson) => Some(geojson),
-                    Err(err) => {
-                        panic!("Invalid GeoJSON ({:?}): {}", err, line);
-                    }
-                }
+    ///
+    /// Parse a single line of the input into GeoJSON
+    ///
+    pub fn parse(mut line: String) -> geojson::GeoJson {
+        //Remove Ascii Record Separators at beginning or end of line
+        if line.ends_with("\u{001E}") {
+            line.pop();
+        } else if line.starts_with("\u{001E}") {
+            line.replace_range(0..1, "");
+        }
+
+        match line.parse::<geojson::GeoJson>() {
+            Ok(geojson) => geojson,
+            Err(err) => {
+                panic!("Invalid GeoJSON ({:?}): {}", err, line);
             }
         }
     }
 }
+
+impl Iterator for GeoStream {
+    type Item = geojson::GeoJson;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_line() {
+            None => None,
+            Some(line) => Some(GeoStream::parse(line)),
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod diacritics;
+mod ordinal;
 mod replace;
+mod synonyms;
 mod titlecase;
 mod tokens;
 
@@ -11,9 +13,11 @@ mod tokens;
 //
 
 pub use self::diacritics::diacritics;
+pub use self::synonyms::{FnSynonym, InputRule, Registry, RuleSynonym, Synonym};
 pub use self::titlecase::titlecase;
-pub use self::tokens::{tokenize_name, ParsedToken, Tokenized, Tokens};
+pub use self::tokens::{tokenize_name, tokenize_names, InputToken, ParsedToken, Tokenized, Tokens};
 
+use self::ordinal::{Gender, Language};
 use crate::{Context, Name, Source};
 use regex::{Regex, RegexSet};
 use std::collections::HashMap;
@@ -71,40 +75,43 @@ where
 
 ///
 /// Is the street a numbered street: ie 1st, 2nd, 3rd etc
+/// Detects ordinals in the notation of the country only, countries without a notation
+/// of their own using the English one, returning the ordinal as written
+/// ie: 1st St, 1re Avenue, 1. Straße, Calle 1ª, 1-я улица
 ///
-pub fn is_numbered(name: &Name) -> Option<String> {
+pub fn is_numbered(name: &Name, country: &str) -> Option<String> {
     let tokens: Vec<String> = name.tokenized.iter().map(|x| x.token.to_owned()).collect();
 
-    lazy_static! {
-        static ref NUMBERED: Regex =
-            Regex::new(r"^(?P<num>([0-9]+)?(1st|2nd|3rd|[0-9]th))$").unwrap();
-    }
-
-    for token in tokens {
-        match NUMBERED.captures(&token) {
-            Some(capture) => {
-                return Some(capture["num"].to_string());
-            }
-            None => (),
-        };
-    }
+    let language = Language::from_country(country).unwrap_or(Language::En);
 
-    None
+    ordinal::parse(&tokens, &name.display, language)
 }
 
 ///
 /// Is the street a route type number
-/// ie: US Route 4
+/// ie: US Route 4, A40, M25
+///
+/// Alphanumeric road numbers are only considered in the country they are used in
 ///
-pub fn is_routish(name: &Name) -> Option<String> {
+pub fn is_routish(name: &Name, country: &str) -> Option<String> {
     let tokens: Vec<String> = name.tokenized.iter().map(|x| x.token.to_owned()).collect();
 
     lazy_static! {
         static ref ROUTISH: Regex = Regex::new(r"^(?P<num>\d+)$").unwrap();
+
+        // GB A/B/M & IE M/N/R roads are alphanumeric
+        static ref GB_ROAD: Regex = Regex::new(r"^(?P<num>\d+|[abm]\d+)$").unwrap();
+        static ref IE_ROAD: Regex = Regex::new(r"^(?P<num>\d+|[mnr]\d+)$").unwrap();
     }
 
+    let routish: &Regex = match country.to_uppercase().as_str() {
+        "GB" => &GB_ROAD,
+        "IE" => &IE_ROAD,
+        _ => &ROUTISH,
+    };
+
     for token in tokens {
-        match ROUTISH.captures(&token) {
+        match routish.captures(&token) {
             Some(capture) => {
                 return Some(capture["num"].to_string());
             }
@@ -143,28 +150,10 @@ pub fn is_drivethrough(text: &String, context: &Context) -> bool {
 }
 
 ///
-/// Detects less desireable feature names
+/// Tokens of less desireable feature names
 /// e.g. US Hwy 125 Ext 1
 ///
-pub fn is_undesireable(tokenized: &Vec<Tokenized>) -> bool {
-    let tokens: Vec<String> = tokenized.iter().map(|x| x.token.to_owned()).collect();
-
-    let subs = vec![
-        String::from("ext"),
-        String::from("connector"),
-        String::from("unit"),
-        String::from("apt"),
-        String::from("apts"),
-        String::from("suite"),
-        String::from("lot"),
-    ];
-    for token in tokens {
-        if subs.contains(&token) {
-            return true;
-        }
-    }
-    false
-}
+pub const UNDESIREABLE: [&str; 7] = ["ext", "connector", "unit", "apt", "apts", "suite", "lot"];
 
 ///
 /// Removes the octothorpe from names like "HWY #35" to get "HWY 35"
@@ -200,21 +189,14 @@ pub fn syn_number_suffix(name: &Name, context: &Context) -> Vec<Name> {
                 }
             };
 
-            let suffix: String;
-            if (num % 100) >= 10 && (num % 100) <= 20 {
-                suffix = String::from("th");
-            } else if (num % 10) == 1 {
-                suffix = String::from("st");
-            } else if (num % 10) == 2 {
-                suffix = String::from("nd");
-            } else if (num % 10) == 3 {
-                suffix = String::from("rd");
-            } else {
-                suffix = String::from("th");
-            }
+            let language = Language::from_country(&context.country).unwrap_or(Language::En);
 
             vec![Name::new(
-                format!("{}{} {}", num, suffix, &capture["name"]),
+                format!(
+                    "{} {}",
+                    ordinal::numeric(num, language, Gender::Neuter),
+                    &capture["name"]
+                ),
                 -1,
                 Some(Source::Generated),
                 &context,
@@ -829,304 +811,774 @@ pub fn syn_state_hwy(name: &Name, context: &Context) -> Vec<Name> {
     syns
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{Context, Name, Tokens};
+///
+/// Generate the compound, abbreviated & separated forms of German street types
+/// "Hauptstraße" <=> "Hauptstr." <=> "Haupt Straße"
+///
+/// Swiss names use "ss" in place of "ß"
+///
+pub fn syn_de_street(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref DE_STREET: Regex = Regex::new(
+            r"(?i)^(?P<pre>\S.*?)(?P<sep>[\s-])?(?P<type>straße|strasse|str\.?|platz|pl\.)$"
+        )
+        .unwrap();
+    }
 
-    #[test]
-    fn test_distance() {
-        assert_eq!(distance(&String::from("a"), &String::from("b")), 1);
-        assert_eq!(distance(&String::from("ab"), &String::from("ac")), 1);
-        assert_eq!(distance(&String::from("ac"), &String::from("bc")), 1);
-        assert_eq!(distance(&String::from("abc"), &String::from("axc")), 1);
-        assert_eq!(
-            distance(
-                &String::from("xabxcdxxefxgx"),
-                &String::from("1ab2cd34ef5g6")
-            ),
-            6
-        );
+    let capture = match DE_STREET.captures(name.display.as_str()) {
+        Some(capture) => capture,
+        None => {
+            return Vec::new();
+        }
+    };
 
-        assert_eq!(
-            distance(&String::from("xabxcdxxefxgx"), &String::from("abcdefg")),
-            6
-        );
-        assert_eq!(
-            distance(&String::from("javawasneat"), &String::from("scalaisgreat")),
-            7
-        );
-        assert_eq!(
-            distance(&String::from("example"), &String::from("samples")),
-            3
-        );
-        assert_eq!(
-            distance(&String::from("forward"), &String::from("drawrof")),
-            6
-        );
-        assert_eq!(
-            distance(&String::from("sturgeon"), &String::from("urgently")),
-            6
-        );
-        assert_eq!(
-            distance(&String::from("levenshtein"), &String::from("frankenstein")),
-            6
-        );
-        assert_eq!(
-            distance(&String::from("distance"), &String::from("difference")),
-            5
-        );
-        assert_eq!(
-            distance(&String::from("distance"), &String::from("eistancd")),
-            2
-        );
+    let pre = capture["pre"].trim_end_matches('-').to_string();
+    let separated = capture.name("sep").is_some();
 
-        assert_eq!(
-            distance(&String::from("你好世界"), &String::from("你好")),
-            2
-        );
-        assert_eq!(
-            distance(
-                &String::from("因為我是中國人所以我會說中文"),
-                &String::from("因為我是英國人所以我會說英文")
-            ),
-            2
-        );
+    // Separated forms are only joined for single word names, excluding
+    // adjectival forms such as "Berliner Straße" which are never joined
+    if separated
+        && (pre.contains(char::is_whitespace)
+            || pre.chars().count() < 3
+            || pre.to_lowercase().ends_with("er"))
+    {
+        return Vec::new();
+    }
 
-        assert_eq!(distance(
-            &String::from("Morbi interdum ultricies neque varius condimentum. Donec volutpat turpis interdum metus ultricies vulputate. Duis ultricies rhoncus sapien, sit amet fermentum risus imperdiet vitae. Ut et lectus"),
-            &String::from("Duis erat dolor, cursus in tincidunt a, lobortis in odio. Cras magna sem, pharetra et iaculis quis, faucibus quis tellus. Suspendisse dapibus sapien in justo cursus")
-        ), 143);
+    let (full, abbr) = if capture["type"].to_lowercase().starts_with("pl") {
+        ("platz", "pl.")
+    } else if context.country == String::from("CH") {
+        ("strasse", "str.")
+    } else {
+        ("straße", "str.")
+    };
+
+    let mut titled = full.chars();
+    let titled = match titled.next() {
+        Some(first) => format!("{}{}", first.to_uppercase(), titled.as_str()),
+        None => String::new(),
+    };
+
+    // Ensure synonyms always have a priority of < 0 and < the original name
+    let priority_offset = std::cmp::min(0, name.priority);
+
+    let mut syns: Vec<Name> = Vec::with_capacity(3);
+
+    for display in vec![
+        format!("{}{}", &pre, full),
+        format!("{}{}", &pre, abbr),
+        format!("{} {}", &pre, &titled),
+    ] {
+        if display.to_lowercase() != name.display.to_lowercase() {
+            syns.push(Name::new(
+                display,
+                priority_offset - 1,
+                Some(Source::Generated),
+                &context,
+            ));
+        }
     }
 
-    #[test]
-    fn test_is_drivethrough() {
-        let context = Context::new(
-            String::from("us"),
-            None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        );
+    distinct(name, syns, &context)
+}
 
-        assert_eq!(
-            is_drivethrough(&String::from("Main St NE"), &context),
-            false
-        );
+///
+/// Generate "ss" variants of names containing "ß" ("Große Gasse" => "Grosse Gasse")
+/// and, outside of Switzerland, "ß" variants of "strasse" ("Strasse des 17. Juni" => "Straße des 17. Juni")
+///
+pub fn syn_de_eszett(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref STRASSE: Regex = Regex::new(r"(?P<s>[Ss])trasse").unwrap();
+    }
 
-        assert_eq!(
-            is_drivethrough(&String::from("McDonalds einfahrt"), &context),
-            false
-        );
+    let priority_offset = std::cmp::min(0, name.priority);
 
-        let context = Context::new(
-            String::from("de"),
-            None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        );
-        assert_eq!(
-            is_drivethrough(&String::from("McDonalds einfahrt"), &context),
-            true
-        );
+    let display = if name.display.contains('ß') {
+        name.display.replace('ß', "ss")
+    } else if context.country != String::from("CH") && STRASSE.is_match(name.display.as_str()) {
+        STRASSE
+            .replace_all(name.display.as_str(), "${s}traße")
+            .to_string()
+    } else {
+        return Vec::new();
+    };
 
-        assert_eq!(
-            is_drivethrough(&String::from("Burger King Drive-through"), &context),
-            true
-        );
+    distinct(
+        name,
+        vec![Name::new(
+            display,
+            priority_offset - 1,
+            Some(Source::Generated),
+            &context,
+        )],
+        &context,
+    )
+}
 
-        assert_eq!(
-            is_drivethrough(&String::from("McDonalds Drivethrough"), &context),
-            true
-        );
+///
+/// Generate "St." variants of names prefixed with "Sankt" and vice versa
+/// "Sankt-Georg-Straße" <=> "St.-Georg-Straße"
+///
+pub fn syn_de_sankt(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref SANKT: Regex =
+            Regex::new(r"(?i)(?P<pre>^|\s|-)(?P<saint>sankt|st\.?)(?P<post>[\s-])").unwrap();
+    }
 
-        assert_eq!(
-            is_drivethrough(&String::from("McDonalds Drive through"), &context),
-            true
-        );
+    let capture = match SANKT.captures(name.display.as_str()) {
+        Some(capture) => capture,
+        None => {
+            return Vec::new();
+        }
+    };
 
-        assert_eq!(
-            is_drivethrough(&String::from("McDonalds Drivethru"), &context),
-            true
-        );
+    let replacement = if capture["saint"].to_lowercase() == "sankt" {
+        "${pre}St.${post}"
+    } else {
+        "${pre}Sankt${post}"
+    };
+
+    let priority_offset = std::cmp::min(0, name.priority);
+
+    distinct(
+        name,
+        vec![Name::new(
+            SANKT
+                .replace(name.display.as_str(), replacement)
+                .to_string(),
+            priority_offset - 1,
+            Some(Source::Generated),
+            &context,
+        )],
+        &context,
+    )
+}
+
+///
+/// Generate synonyms of French names without their articles & particles
+/// "Rue de la Paix" => "Rue Paix", "Avenue du Général Leclerc" => "Avenue Général Leclerc"
+///
+pub fn syn_fr_particle(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref FR_PARTICLE: Regex = Regex::new(
+            r"(?i)^(?P<type>\S+)\s(de\sla\s|de\sl['’]|des\s|du\s|de\s|d['’])(?P<rest>\S.*)$"
+        )
+        .unwrap();
     }
 
-    #[test]
-    fn test_is_undesireable() {
-        let tokens = Tokens::generate(vec![String::from("en")]);
+    let capture = match FR_PARTICLE.captures(name.display.as_str()) {
+        Some(capture) => capture,
+        None => {
+            return Vec::new();
+        }
+    };
 
-        assert_eq!(
-            is_undesireable(&tokens.process(&String::from("Main St NE"), &String::from(""))),
-            false
-        );
-        assert_eq!(
-            is_undesireable(&tokens.process(&String::from("Main St NE Ext 25"), &String::from(""))),
-            true
-        );
-        assert_eq!(
-            is_undesireable(
-                &tokens.process(&String::from("Main St NE Connector 25"), &String::from(""))
-            ),
-            true
-        );
-        assert_eq!(
-            is_undesireable(
-                &tokens.process(&String::from("Main St NE Unit 25"), &String::from(""))
-            ),
-            true
-        );
-        assert_eq!(
-            is_undesireable(
-                &tokens.process(&String::from("Main St NE Apartment 25"), &String::from(""))
-            ),
-            true
-        );
-        assert_eq!(
-            is_undesireable(
-                &tokens.process(&String::from("Main St NE Shelby Apts"), &String::from(""))
-            ),
-            true
-        );
-        assert_eq!(
-            is_undesireable(
-                &tokens.process(&String::from("Main St NE Suite 25"), &String::from(""))
-            ),
-            true
-        );
-        assert_eq!(
-            is_undesireable(&tokens.process(&String::from("Main St NE Lot 25"), &String::from(""))),
-            true
-        );
+    let mut rest = capture["rest"].chars();
+    let rest = match rest.next() {
+        Some(first) => format!("{}{}", first.to_uppercase(), rest.as_str()),
+        None => {
+            return Vec::new();
+        }
+    };
+
+    let priority_offset = std::cmp::min(0, name.priority);
+
+    distinct(
+        name,
+        vec![Name::new(
+            format!("{} {}", &capture["type"], rest),
+            priority_offset - 1,
+            Some(Source::Generated),
+            &context,
+        )],
+        &context,
+    )
+}
+
+///
+/// Generate abbreviated & expanded variants of French saint names, separated by either a hyphen or space
+/// "Rue Saint-Jean" <=> "Rue St-Jean", "Rue St Jean", "Rue Saint Jean"
+///
+pub fn syn_fr_saint(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref FR_SAINT: Regex =
+            Regex::new(r"(?i)(?P<pre>^|[\s-])(?P<saint>sainte|saint|ste|st)\.?(?P<post>[\s-])")
+                .unwrap();
     }
 
-    #[test]
-    fn test_syn_us_famous() {
-        let mut context = Context::new(
-            String::from("us"),
-            None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        );
+    if !FR_SAINT.is_match(name.display.as_str()) {
+        return Vec::new();
+    }
 
-        assert_eq!(
-            syn_us_famous(&Name::new(String::from(""), 0, None, &context), &context),
-            vec![]
-        );
+    // Swap the abbreviated & expanded forms and/or the hyphen & space following them
+    let variant = |swap_saint: bool, swap_sep: bool| -> String {
+        FR_SAINT
+            .replace_all(name.display.as_str(), |capture: &regex::Captures| {
+                let saint = match (swap_saint, capture["saint"].to_lowercase().as_str()) {
+                    (false, _) => capture["saint"].to_string(),
+                    (true, "sainte") => String::from("Ste"),
+                    (true, "saint") => String::from("St"),
+                    (true, "ste") => String::from("Sainte"),
+                    (true, _) => String::from("Saint"),
+                };
 
-        // original name priority == 0
-        let results = vec![
-            Name::new("John F Kennedy", 1, Some(Source::Generated), &context),
-            Name::new("JFK", -1, Some(Source::Generated), &context),
-        ];
-        assert_eq!(
-            syn_us_famous(&Name::new(String::from("JFK"), 0, None, &context), &context),
-            results
-        );
-        assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("J.F.K."), 0, None, &context),
-                &context
-            ),
-            results
+                let post = match (swap_sep, &capture["post"]) {
+                    (false, post) => post,
+                    (true, "-") => " ",
+                    (true, _) => "-",
+                };
+
+                format!("{}{}{}", &capture["pre"], saint, post)
+            })
+            .to_string()
+    };
+
+    let priority_offset = std::cmp::min(0, name.priority);
+
+    distinct(
+        name,
+        vec![
+            variant(true, false),
+            variant(true, true),
+            variant(false, true),
+        ]
+        .into_iter()
+        .map(|display| {
+            Name::new(
+                display,
+                priority_offset - 1,
+                Some(Source::Generated),
+                &context,
+            )
+        })
+        .collect(),
+        &context,
+    )
+}
+
+///
+/// Generate synonyms for GB & IE road numbers
+/// "A40" <=> "A40 Road", "M25" <=> "M25 Motorway"
+///
+/// GB roads are A, B or M (Motorway) roads, IE roads are N, R or M (Motorway) roads
+///
+pub fn syn_gb_road(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref GB_ROAD: Regex =
+            Regex::new(r"(?i)^(?P<road>[abmnr])(?P<num>\d+)(\s(?P<type>road|rd\.?|motorway))?$")
+                .unwrap();
+    }
+
+    let capture = match GB_ROAD.captures(name.display.as_str()) {
+        Some(capture) => capture,
+        None => {
+            return Vec::new();
+        }
+    };
+
+    let road = capture["road"].to_uppercase();
+
+    let valid = if context.country == String::from("IE") {
+        vec!["M", "N", "R"]
+    } else {
+        vec!["A", "B", "M"]
+    };
+
+    if !valid.contains(&road.as_str()) {
+        return Vec::new();
+    }
+
+    let reference = format!("{}{}", road, &capture["num"]);
+
+    let display = match capture.name("type") {
+        Some(_) => reference,
+        None if road == "M" => format!("{} Motorway", reference),
+        None => format!("{} Road", reference),
+    };
+
+    let priority_offset = std::cmp::min(0, name.priority);
+
+    distinct(
+        name,
+        vec![Name::new(
+            display,
+            priority_offset - 1,
+            Some(Source::Generated),
+            &context,
+        )],
+        &context,
+    )
+}
+
+///
+/// Generate synonyms for AU national, state & alphanumeric routes
+/// "National Route 1" => "National Highway 1", "Highway 1"
+/// "State Route 12" => "Highway 12"
+/// "M1" <=> "Route M1"
+///
+pub fn syn_au_hwy(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref AU_NATIONAL: Regex =
+            Regex::new(r"(?i)^(national\s(route|highway|hwy)|nr|nh)\s?(?P<num>\d+)$").unwrap();
+        static ref AU_STATE: Regex =
+            Regex::new(r"(?i)^(state\s(route|highway|hwy)|sr)\s?(?P<num>\d+)$").unwrap();
+        static ref AU_ALPHA: Regex = Regex::new(r"(?i)^(route\s)?(?P<route>[mabc]\d+)$").unwrap();
+    }
+
+    let display = name.display.as_str();
+
+    let syns = if let Some(capture) = AU_NATIONAL.captures(display) {
+        vec![
+            format!("National Route {}", &capture["num"]),
+            format!("National Highway {}", &capture["num"]),
+            format!("Highway {}", &capture["num"]),
+        ]
+    } else if let Some(capture) = AU_STATE.captures(display) {
+        vec![
+            format!("State Route {}", &capture["num"]),
+            format!("Highway {}", &capture["num"]),
+        ]
+    } else if let Some(capture) = AU_ALPHA.captures(display) {
+        let route = capture["route"].to_uppercase();
+
+        vec![route.clone(), format!("Route {}", route)]
+    } else {
+        return Vec::new();
+    };
+
+    generated(name, context, syns)
+}
+
+///
+/// Generate synonyms for NZ state highways
+/// "State Highway 1" <=> "SH1" <=> "Highway 1"
+///
+pub fn syn_nz_hwy(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref NZ_HWY: Regex =
+            Regex::new(r"(?i)^(state\s(highway|hwy)|sh|highway|hwy)\s?(?P<num>\d+)$").unwrap();
+    }
+
+    let capture = match NZ_HWY.captures(name.display.as_str()) {
+        Some(capture) => capture,
+        None => {
+            return Vec::new();
+        }
+    };
+
+    generated(
+        name,
+        context,
+        vec![
+            format!("State Highway {}", &capture["num"]),
+            format!("SH{}", &capture["num"]),
+            format!("Highway {}", &capture["num"]),
+        ],
+    )
+}
+
+///
+/// Generate synonyms for ES & Latin American street types
+/// "Calle 5" <=> "5", "Carrera 7" <=> "Cra 7" <=> "Kr 7",
+/// "Avenida Juárez" <=> "Av Juárez" <=> "Avda Juárez"
+///
+/// "Calle" is only left out of numbered calles, "Calle Mayor" is never "Mayor"
+///
+pub fn syn_es_street(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref ES_STREET: Regex = Regex::new(
+            r"(?i)^(?:(?P<type>calle|cll?|carrera|cra|kra?|cr|avenida|avda|avd|av)\.?\s+|c/\s*)(?P<rest>\S.*)$"
+        )
+        .unwrap();
+        // Numbered or ordinal calles, ie: 5, 26a, 10 bis, 5ª, 3º
+        static ref ES_BARE: Regex =
+            Regex::new(r"(?i)^\d+(?:[a-z]|\s?[ªº°])?(\sbis)?$").unwrap();
+    }
+
+    let display = name.display.as_str();
+
+    if ES_BARE.is_match(display) {
+        return generated(name, context, vec![format!("Calle {}", display)]);
+    }
+
+    let capture = match ES_STREET.captures(display) {
+        Some(capture) => capture,
+        None => {
+            return Vec::new();
+        }
+    };
+
+    let rest = &capture["rest"];
+
+    let kind = match capture.name("type") {
+        Some(kind) => kind.as_str().to_lowercase(),
+        None => String::from("calle"),
+    };
+
+    let syns = match kind.as_str() {
+        "calle" | "cl" | "cll" => {
+            if ES_BARE.is_match(rest) {
+                vec![format!("Calle {}", rest), rest.to_string()]
+            } else {
+                vec![format!("Calle {}", rest)]
+            }
+        }
+        "carrera" | "cra" | "kr" | "kra" | "cr" => vec![
+            format!("Carrera {}", rest),
+            format!("Cra {}", rest),
+            format!("Kr {}", rest),
+        ],
+        _ => vec![
+            format!("Avenida {}", rest),
+            format!("Av {}", rest),
+            format!("Avda {}", rest),
+        ],
+    };
+
+    generated(name, context, syns)
+}
+
+///
+/// Generate synonyms of ES & Latin American names without their honorifics
+/// "Avenida General Paz" => "Avenida Paz", "Presidente Masaryk" => "Masaryk"
+///
+pub fn syn_es_honorific(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref ES_HONORIFIC: Regex = Regex::new(
+            r"(?i)(?P<pre>^|\s)(general|gral|presidente|pdte|pte|doctor|dr|ingeniero|ing|coronel|cnel)\.?\s+(?P<post>\S)"
+        )
+        .unwrap();
+    }
+
+    if !ES_HONORIFIC.is_match(name.display.as_str()) {
+        return Vec::new();
+    }
+
+    let display = ES_HONORIFIC.replace_all(name.display.as_str(), "$pre$post");
+
+    generated(name, context, vec![display.to_string()])
+}
+
+///
+/// Generate numeric synonyms of written ordinals in languages other than English,
+/// which are handled by syn_written_numeric & geocoder-abbreviations
+/// "Première Avenue" => "1re Avenue", "Zweite Straße" => "2. Straße", "Calle Primera" => "Calle 1ª"
+///
+pub fn syn_written_ordinal(name: &Name, context: &Context) -> Vec<Name> {
+    lazy_static! {
+        static ref WORD: Regex = Regex::new(r"\w+").unwrap();
+    }
+
+    let language = match Language::from_country(&context.country) {
+        None | Some(Language::En) => {
+            return Vec::new();
+        }
+        Some(language) => language,
+    };
+
+    let display =
+        WORD.replace_all(
+            name.display.as_str(),
+            |capture: &regex::Captures| match ordinal::written(&capture[0], language) {
+                Some((num, gender)) => ordinal::numeric(num, language, gender),
+                None => capture[0].to_string(),
+            },
         );
+
+    if display == name.display {
+        return Vec::new();
+    }
+
+    generated(name, context, vec![display.to_string()])
+}
+
+///
+/// Create generated synonyms of a name from a list of display forms,
+/// skipping the display form of the name itself
+///
+fn generated(name: &Name, context: &Context, displays: Vec<String>) -> Vec<Name> {
+    // Ensure synonyms always have a priority of < 0 and < the original name
+    let priority_offset = std::cmp::min(0, name.priority);
+
+    let syns = displays
+        .into_iter()
+        .filter(|display| display.to_lowercase() != name.display.to_lowercase())
+        .map(|display| {
+            Name::new(
+                display,
+                priority_offset - 1,
+                Some(Source::Generated),
+                &context,
+            )
+        })
+        .collect();
+
+    distinct(name, syns, &context)
+}
+
+///
+/// Remove synonyms that dedupe to the same key as the original name.
+/// These add nothing to search & would replace the display form of the
+/// original name when the names are deduped
+///
+fn distinct(name: &Name, mut syns: Vec<Name>, context: &Context) -> Vec<Name> {
+    let key = name.dedupe_key(&context.country);
+
+    syns.retain(|syn| syn.dedupe_key(&context.country) != key);
+
+    syns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Context, Name, Names, Tokens};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(&String::from("a"), &String::from("b")), 1);
+        assert_eq!(distance(&String::from("ab"), &String::from("ac")), 1);
+        assert_eq!(distance(&String::from("ac"), &String::from("bc")), 1);
+        assert_eq!(distance(&String::from("abc"), &String::from("axc")), 1);
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("J F K"), 0, None, &context),
-                &context
+            distance(
+                &String::from("xabxcdxxefxgx"),
+                &String::from("1ab2cd34ef5g6")
             ),
-            results
+            6
         );
+
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("J. F. K."), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("xabxcdxxefxgx"), &String::from("abcdefg")),
+            6
         );
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("John F Kennedy"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("javawasneat"), &String::from("scalaisgreat")),
+            7
         );
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("John F. Kennedy"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("example"), &String::from("samples")),
+            3
         );
-
-        let results = vec![
-            Name::new(
-                "John F Kennedy Highway",
-                1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new("JFK Highway", -1, Some(Source::Generated), &context),
-        ];
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("JFK Highway"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("forward"), &String::from("drawrof")),
+            6
         );
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("J.F.K Highway"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("sturgeon"), &String::from("urgently")),
+            6
         );
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("J F K Highway"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("levenshtein"), &String::from("frankenstein")),
+            6
         );
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("J. F. K. Highway"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("distance"), &String::from("difference")),
+            5
         );
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("John F Kennedy Highway"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("distance"), &String::from("eistancd")),
+            2
         );
 
-        let results = vec![
-            Name::new(
-                "NE John F Kennedy Highway",
-                1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new("NE JFK Highway", -1, Some(Source::Generated), &context),
-        ];
-
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("NE JFK Highway"), 0, None, &context),
-                &context
-            ),
-            results
+            distance(&String::from("你好世界"), &String::from("你好")),
+            2
         );
         assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("NE J.F.K Highway"), 0, None, &context),
-                &context
+            distance(
+                &String::from("因為我是中國人所以我會說中文"),
+                &String::from("因為我是英國人所以我會說英文")
             ),
-            results
+            2
         );
-        assert_eq!(
-            syn_us_famous(
-                &Name::new(String::from("NE J F K Highway"), 0, None, &context),
-                &context
+
+        assert_eq!(distance(
+            &String::from("Morbi interdum ultricies neque varius condimentum. Donec volutpat turpis interdum metus ultricies vulputate. Duis ultricies rhoncus sapien, sit amet fermentum risus imperdiet vitae. Ut et lectus"),
+            &String::from("Duis erat dolor, cursus in tincidunt a, lobortis in odio. Cras magna sem, pharetra et iaculis quis, faucibus quis tellus. Suspendisse dapibus sapien in justo cursus")
+        ), 143);
+    }
+
+    #[test]
+    fn test_is_drivethrough() {
+        let context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            is_drivethrough(&String::from("Main St NE"), &context),
+            false
+        );
+
+        assert_eq!(
+            is_drivethrough(&String::from("McDonalds einfahrt"), &context),
+            false
+        );
+
+        let context = Context::new(
+            String::from("de"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            is_drivethrough(&String::from("McDonalds einfahrt"), &context),
+            true
+        );
+
+        assert_eq!(
+            is_drivethrough(&String::from("Burger King Drive-through"), &context),
+            true
+        );
+
+        assert_eq!(
+            is_drivethrough(&String::from("McDonalds Drivethrough"), &context),
+            true
+        );
+
+        assert_eq!(
+            is_drivethrough(&String::from("McDonalds Drive through"), &context),
+            true
+        );
+
+        assert_eq!(
+            is_drivethrough(&String::from("McDonalds Drivethru"), &context),
+            true
+        );
+    }
+
+    #[test]
+    fn test_syn_us_famous() {
+        let mut context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_us_famous(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
+        );
+
+        // original name priority == 0
+        let results = vec![
+            Name::new("John F Kennedy", 1, Some(Source::Generated), &context),
+            Name::new("JFK", -1, Some(Source::Generated), &context),
+        ];
+        assert_eq!(
+            syn_us_famous(&Name::new(String::from("JFK"), 0, None, &context), &context),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("J.F.K."), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("J F K"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("J. F. K."), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("John F Kennedy"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("John F. Kennedy"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+
+        let results = vec![
+            Name::new(
+                "John F Kennedy Highway",
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new("JFK Highway", -1, Some(Source::Generated), &context),
+        ];
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("JFK Highway"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("J.F.K Highway"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("J F K Highway"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("J. F. K. Highway"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("John F Kennedy Highway"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+
+        let results = vec![
+            Name::new(
+                "NE John F Kennedy Highway",
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new("NE JFK Highway", -1, Some(Source::Generated), &context),
+        ];
+
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("NE JFK Highway"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("NE J.F.K Highway"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_famous(
+                &Name::new(String::from("NE J F K Highway"), 0, None, &context),
+                &context
             ),
             results
         );
@@ -1522,7 +1974,7 @@ mod tests {
         context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         let input = vec![
@@ -1714,941 +2166,2139 @@ mod tests {
             ],
             output
         );
-    }
+    }
+
+    #[test]
+    fn test_syn_us_cr() {
+        let context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_us_cr(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
+        );
+
+        // original name priority == 0
+        let results = vec![
+            Name::new(
+                String::from("County Road 123"),
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("CR 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_us_cr(
+                &Name::new(String::from("County Road 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_cr(
+                &Name::new(String::from("CR 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority < 0
+        let results = vec![
+            Name::new(
+                String::from("County Road 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("CR 123"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_us_cr(
+                &Name::new(String::from("County Road 123"), -1, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority > 0
+        let results = vec![
+            Name::new(
+                String::from("County Road 123"),
+                2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("CR 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_us_cr(
+                &Name::new(String::from("County Road 123"), 1, None, &context),
+                &context
+            ),
+            results
+        );
+    }
+
+    #[test]
+    fn test_syn_ca_french() {
+        let context = Context::new(
+            String::from("ca"),
+            Some(String::from("qc")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_ca_french(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
+        );
+
+        // Successful Replacements
+        assert_eq!(
+            syn_ca_french(
+                &Name::new(String::from("r principale"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("principale"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        // Ignored Replacements
+        assert_eq!(
+            syn_ca_french(
+                &Name::new(String::from("r des peupliers"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+        assert_eq!(
+            syn_ca_french(
+                &Name::new(String::from("ch des hauteurs"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+        assert_eq!(
+            syn_ca_french(
+                &Name::new(String::from("r du blizzard"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+        assert_eq!(
+            syn_ca_french(
+                &Name::new(String::from("bd de lhotel de vl"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_syn_ny_beach() {
+        let context = Context::new(
+            String::from("us"),
+            Some(String::from("ny")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_ny_beach(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
+        );
+
+        // original name priority == 0
+        let results = vec![
+            Name::new(
+                String::from("Beach 31st St"),
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("B 31st St"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_ny_beach(
+                &Name::new(String::from("Beach 31st St"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ny_beach(
+                &Name::new(String::from("Bch 31st St"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ny_beach(
+                &Name::new(String::from("B 31st St"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+        assert_eq!(
+            syn_ny_beach(
+                &Name::new(String::from("9B 31st St"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+
+        // original name priority < 0
+        let results = vec![
+            Name::new(
+                String::from("Beach 31st St"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("B 31st St"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_ny_beach(
+                &Name::new(String::from("Beach 31st St"), -1, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority > 0
+        let results = vec![
+            Name::new(
+                String::from("Beach 31st St"),
+                2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("B 31st St"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_ny_beach(
+                &Name::new(String::from("Beach 31st St"), 1, None, &context),
+                &context
+            ),
+            results
+        );
+    }
+
+    #[test]
+    fn test_syn_ca_hwy() {
+        // Route preferencing proveninces
+        let context = Context::new(
+            String::from("ca"),
+            Some(String::from("on")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_ca_hwy(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
+        );
+        // handle Trans Canada highways
+        assert_eq!(
+            syn_ca_hwy(&Name::new(String::from("1"), 0, None, &context), &context),
+            vec![]
+        );
+
+        // original name priority == 0
+        let results = vec![
+            Name::new(
+                String::from("Ontario Route 101"),
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Highway 101"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Route 101"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("ON 101"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_ca_hwy(&Name::new(String::from("101"), 0, None, &context), &context),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("ON-101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("Kings's Highway 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("Highway 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("Route 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("Ontario Highway 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // Highway preferencing proveninces
+        let context = Context::new(
+            String::from("ca"),
+            Some(String::from("nb")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        let results = vec![
+            Name::new(
+                String::from("New Brunswick Highway 101"),
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Highway 101"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Route 101"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("NB 101"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_ca_hwy(&Name::new(String::from("101"), 0, None, &context), &context),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("NB-101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("Kings's Highway 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("Highway 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("Route 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("New Brunswick Highway 101"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority < 0
+        let results = vec![
+            Name::new(
+                String::from("New Brunswick Highway 101"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Highway 101"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Route 101"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("NB 101"),
+                -3,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_ca_hwy(
+                &Name::new(String::from("101"), -1, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority > 0
+        let results = vec![
+            Name::new(
+                String::from("New Brunswick Highway 101"),
+                2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Highway 101"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Route 101"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("NB 101"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_ca_hwy(&Name::new(String::from("101"), 1, None, &context), &context),
+            results
+        );
+    }
+
+    #[test]
+    fn test_syn_us_hwy() {
+        let context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_us_hwy(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
+        );
+
+        // original name priority == 0
+        let results = vec![
+            Name::new(
+                String::from("US Route 81"),
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(String::from("US 81"), -1, Some(Source::Generated), &context),
+            Name::new(
+                String::from("US Highway 81"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("United States Route 81"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("United States Highway 81"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("us-81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("US 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("U.S. Route 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("US Route 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("US Rte 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("US Hwy 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("US Highway 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("United States 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("United States Route 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("United States Highway 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("United States Hwy 81"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority < 0
+        let results = vec![
+            Name::new(
+                String::from("US Route 81"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(String::from("US 81"), -2, Some(Source::Generated), &context),
+            Name::new(
+                String::from("US Highway 81"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("United States Route 81"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("United States Highway 81"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("US Route 81"), -1, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority > 0
+        let results = vec![
+            Name::new(
+                String::from("US Route 81"),
+                2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(String::from("US 81"), -1, Some(Source::Generated), &context),
+            Name::new(
+                String::from("US Highway 81"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("United States Route 81"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("United States Highway 81"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+
+        assert_eq!(
+            syn_us_hwy(
+                &Name::new(String::from("US Route 81"), 1, None, &context),
+                &context
+            ),
+            results
+        );
+    }
+
+    #[test]
+    fn test_syn_state_hwy() {
+        let context = Context::new(
+            String::from("us"),
+            Some(String::from("PA")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_state_hwy(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
+        );
+
+        // original name priority == 0
+        let results = vec![
+            Name::new(
+                String::from("Pennsylvania Highway 123"),
+                1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("PA 123 Highway"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("PA 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Highway 123"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("SR 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("State Highway 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("State Route 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("St Hwy 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("St Rte 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("State Highway 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("Highway 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("Hwy 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("Pennsylvania Highway 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("Pennsylvania Route 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("PA 123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("PA-123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("US-PA-123"), 0, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority < 0
+        let results = vec![
+            Name::new(
+                String::from("Pennsylvania Highway 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("PA 123 Highway"),
+                -3,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("PA 123"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Highway 123"),
+                -3,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("SR 123"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("State Highway 123"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("State Route 123"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("Pennsylvania Highway 123"), -1, None, &context),
+                &context
+            ),
+            results
+        );
+
+        // original name priority > 0
+        let results = vec![
+            Name::new(
+                String::from("Pennsylvania Highway 123"),
+                2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("PA 123 Highway"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("PA 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("Highway 123"),
+                -2,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("SR 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("State Highway 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+            Name::new(
+                String::from("State Route 123"),
+                -1,
+                Some(Source::Generated),
+                &context,
+            ),
+        ];
+        assert_eq!(
+            syn_state_hwy(
+                &Name::new(String::from("Pennsylvania Highway 123"), 1, None, &context),
+                &context
+            ),
+            results
+        );
+    }
+
+    #[test]
+    fn test_syn_number_suffix() {
+        let context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_number_suffix(
+                &Name::new(String::from("1st Avenue"), 0, None, &context),
+                &context
+            ),
+            Vec::new()
+        );
+
+        assert_eq!(
+            syn_number_suffix(
+                &Name::new(String::from("1 Avenue"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("1st Avenue"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_number_suffix(
+                &Name::new(String::from("2 Avenue"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("2nd Avenue"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_number_suffix(
+                &Name::new(String::from("3 Street"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("3rd Street"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_number_suffix(
+                &Name::new(String::from("4 Street"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("4th Street"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_number_suffix(
+                &Name::new(String::from("20 Street"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("20th Street"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_number_suffix(
+                &Name::new(String::from("21 Street"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("21st Street"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+    }
+
+    #[test]
+    fn test_syn_written_numeric() {
+        let context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_written_numeric(
+                &Name::new(String::from("Twenty-third Avenue NW"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("23rd Avenue NW"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_written_numeric(
+                &Name::new(String::from("North twenty-Third Avenue"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("North 23rd Avenue"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_written_numeric(
+                &Name::new(String::from("TWENTY-THIRD Avenue"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("23rd Avenue"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+    }
+
+    #[test]
+    fn test_str_remove_octo() {
+        assert_eq!(
+            str_remove_octo(&String::from("Highway #12 West")),
+            String::from("Highway 12 West")
+        );
+
+        assert_eq!(
+            str_remove_octo(&String::from("RTe #1")),
+            String::from("RTe 1")
+        );
+    }
+
+    #[test]
+    fn test_is_numbered() {
+        let context = Context::new(
+            String::from("us"),
+            Some(String::from("PA")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("main st"), 0, None, &context),
+                &context.country
+            ),
+            None
+        );
+
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("1st st"), 0, None, &context),
+                &context.country
+            ),
+            Some(String::from("1st"))
+        );
 
-    #[test]
-    fn test_syn_us_cr() {
-        let context = Context::new(
-            String::from("us"),
-            None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("2nd st"), 0, None, &context),
+                &context.country
+            ),
+            Some(String::from("2nd"))
         );
 
         assert_eq!(
-            syn_us_cr(&Name::new(String::from(""), 0, None, &context), &context),
-            vec![]
+            is_numbered(
+                &Name::new(String::from("west 2nd st"), 0, None, &context),
+                &context.country
+            ),
+            Some(String::from("2nd"))
         );
 
-        // original name priority == 0
-        let results = vec![
-            Name::new(
-                String::from("County Road 123"),
-                1,
-                Some(Source::Generated),
-                &context,
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("3rd st"), 0, None, &context),
+                &context.country
             ),
-            Name::new(
-                String::from("CR 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
+            Some(String::from("3rd"))
+        );
+
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("4th st"), 0, None, &context),
+                &context.country
             ),
-        ];
+            Some(String::from("4th"))
+        );
+
         assert_eq!(
-            syn_us_cr(
-                &Name::new(String::from("County Road 123"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("11th ave"), 0, None, &context),
+                &context.country
             ),
-            results
+            Some(String::from("11th"))
         );
+
         assert_eq!(
-            syn_us_cr(
-                &Name::new(String::from("CR 123"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("12th ave"), 0, None, &context),
+                &context.country
             ),
-            results
+            Some(String::from("12th"))
         );
 
-        // original name priority < 0
-        let results = vec![
-            Name::new(
-                String::from("County Road 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("21st av"), 0, None, &context),
+                &context.country
             ),
-            Name::new(
-                String::from("CR 123"),
-                -2,
-                Some(Source::Generated),
-                &context,
+            Some(String::from("21st"))
+        );
+
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("32nd av"), 0, None, &context),
+                &context.country
             ),
-        ];
+            Some(String::from("32nd"))
+        );
+
         assert_eq!(
-            syn_us_cr(
-                &Name::new(String::from("County Road 123"), -1, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("45th av"), 0, None, &context),
+                &context.country
             ),
-            results
+            Some(String::from("45th"))
         );
 
-        // original name priority > 0
-        let results = vec![
-            Name::new(
-                String::from("County Road 123"),
-                2,
-                Some(Source::Generated),
-                &context,
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("351235th av"), 0, None, &context),
+                &context.country
             ),
-            Name::new(
-                String::from("CR 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
+            Some(String::from("351235th"))
+        );
+
+        // Only the ordinal notation of the country is recognized
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("2e Rue"), 0, None, &context),
+                &context.country
             ),
-        ];
+            None
+        );
+
         assert_eq!(
-            syn_us_cr(
-                &Name::new(String::from("County Road 123"), 1, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("1. Straße"), 0, None, &context),
+                &context.country
             ),
-            results
+            None
         );
-    }
 
-    #[test]
-    fn test_syn_ca_french() {
         let context = Context::new(
-            String::from("ca"),
-            Some(String::from("qc")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            String::from("mx"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            syn_ca_french(&Name::new(String::from(""), 0, None, &context), &context),
-            vec![]
+            is_numbered(
+                &Name::new(String::from("Calle 1ª"), 0, None, &context),
+                &context.country
+            ),
+            Some(String::from("1ª"))
         );
 
-        // Successful Replacements
         assert_eq!(
-            syn_ca_french(
-                &Name::new(String::from("r principale"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("5º Avenida"), 0, None, &context),
+                &context.country
             ),
-            vec![Name::new(
-                String::from("principale"),
-                -1,
-                Some(Source::Generated),
-                &context
-            )]
+            Some(String::from("5º"))
         );
 
-        // Ignored Replacements
         assert_eq!(
-            syn_ca_french(
-                &Name::new(String::from("r des peupliers"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("2da Calle"), 0, None, &context),
+                &context.country
             ),
-            vec![]
+            Some(String::from("2da"))
         );
+
         assert_eq!(
-            syn_ca_french(
-                &Name::new(String::from("ch des hauteurs"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("Calle 5"), 0, None, &context),
+                &context.country
             ),
-            vec![]
+            None
+        );
+
+        let context = Context::new(
+            String::from("fr"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
+
         assert_eq!(
-            syn_ca_french(
-                &Name::new(String::from("r du blizzard"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("1re Avenue"), 0, None, &context),
+                &context.country
             ),
-            vec![]
+            Some(String::from("1re"))
         );
+
         assert_eq!(
-            syn_ca_french(
-                &Name::new(String::from("bd de lhotel de vl"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("2e Rue"), 0, None, &context),
+                &context.country
             ),
-            vec![]
+            Some(String::from("2e"))
+        );
+
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("21st Av"), 0, None, &context),
+                &context.country
+            ),
+            None
         );
-    }
 
-    #[test]
-    fn test_syn_ny_beach() {
         let context = Context::new(
-            String::from("us"),
-            Some(String::from("ny")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            String::from("nl"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            syn_ny_beach(&Name::new(String::from(""), 0, None, &context), &context),
-            vec![]
+            is_numbered(
+                &Name::new(String::from("3de Laan"), 0, None, &context),
+                &context.country
+            ),
+            Some(String::from("3de"))
         );
 
-        // original name priority == 0
-        let results = vec![
-            Name::new(
-                String::from("Beach 31st St"),
-                1,
-                Some(Source::Generated),
-                &context,
+        let context = Context::new(
+            String::from("de"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("1. Straße"), 0, None, &context),
+                &context.country
             ),
-            Name::new(
-                String::from("B 31st St"),
-                -1,
-                Some(Source::Generated),
-                &context,
+            Some(String::from("1."))
+        );
+
+        assert_eq!(
+            is_numbered(
+                &Name::new(String::from("2e Rue"), 0, None, &context),
+                &context.country
             ),
-        ];
+            None
+        );
+
+        let context = Context::new(
+            String::from("ru"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
         assert_eq!(
-            syn_ny_beach(
-                &Name::new(String::from("Beach 31st St"), 0, None, &context),
-                &context
+            is_numbered(
+                &Name::new(String::from("1-я улица"), 0, None, &context),
+                &context.country
             ),
-            results
+            Some(String::from("1-я"))
+        );
+    }
+
+    #[test]
+    fn test_is_routish() {
+        let context = Context::new(
+            String::from("us"),
+            Some(String::from("PA")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
+
         assert_eq!(
-            syn_ny_beach(
-                &Name::new(String::from("Bch 31st St"), 0, None, &context),
-                &context
+            is_routish(
+                &Name::new(String::from("main st"), 0, None, &context),
+                &context.country
             ),
-            results
+            None
         );
+
         assert_eq!(
-            syn_ny_beach(
-                &Name::new(String::from("B 31st St"), 0, None, &context),
-                &context
+            is_routish(
+                &Name::new(String::from("1st st"), 0, None, &context),
+                &context.country
             ),
-            vec![]
+            None
         );
+
         assert_eq!(
-            syn_ny_beach(
-                &Name::new(String::from("9B 31st St"), 0, None, &context),
-                &context
+            is_routish(
+                &Name::new(String::from("351235th av"), 0, None, &context),
+                &context.country
             ),
-            vec![]
+            None
         );
 
-        // original name priority < 0
-        let results = vec![
-            Name::new(
-                String::from("Beach 31st St"),
-                -1,
-                Some(Source::Generated),
-                &context,
+        assert_eq!(
+            is_routish(
+                &Name::new(String::from("NC 124"), 0, None, &context),
+                &context.country
             ),
-            Name::new(
-                String::from("B 31st St"),
-                -2,
-                Some(Source::Generated),
-                &context,
+            Some(String::from("124"))
+        );
+
+        assert_eq!(
+            is_routish(
+                &Name::new(String::from("US Route 50 East"), 0, None, &context),
+                &context.country
             ),
-        ];
+            Some(String::from("50"))
+        );
+
         assert_eq!(
-            syn_ny_beach(
-                &Name::new(String::from("Beach 31st St"), -1, None, &context),
-                &context
+            is_routish(
+                &Name::new(String::from("321"), 0, None, &context),
+                &context.country
             ),
-            results
+            Some(String::from("321"))
         );
 
-        // original name priority > 0
-        let results = vec![
-            Name::new(
-                String::from("Beach 31st St"),
-                2,
-                Some(Source::Generated),
-                &context,
+        assert_eq!(
+            is_routish(
+                &Name::new(String::from("124 NC"), 0, None, &context),
+                &context.country
             ),
-            Name::new(
-                String::from("B 31st St"),
-                -1,
-                Some(Source::Generated),
-                &context,
+            Some(String::from("124"))
+        );
+
+        // Alphanumeric roads are only routes in GB & IE
+        assert_eq!(
+            is_routish(
+                &Name::new(String::from("A40"), 0, None, &context),
+                &context.country
             ),
-        ];
+            None
+        );
+
         assert_eq!(
-            syn_ny_beach(
-                &Name::new(String::from("Beach 31st St"), 1, None, &context),
-                &context
+            is_routish(
+                &Name::new(String::from("M25 Motorway"), 0, None, &context),
+                &context.country
             ),
-            results
+            None
         );
-    }
 
-    #[test]
-    fn test_syn_ca_hwy() {
-        // Route preferencing proveninces
         let context = Context::new(
-            String::from("ca"),
-            Some(String::from("on")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            String::from("gb"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            syn_ca_hwy(&Name::new(String::from(""), 0, None, &context), &context),
-            vec![]
+            is_routish(
+                &Name::new(String::from("A40"), 0, None, &context),
+                &context.country
+            ),
+            Some(String::from("a40"))
         );
-        // handle Trans Canada highways
+
         assert_eq!(
-            syn_ca_hwy(&Name::new(String::from("1"), 0, None, &context), &context),
-            vec![]
+            is_routish(
+                &Name::new(String::from("M25 Motorway"), 0, None, &context),
+                &context.country
+            ),
+            Some(String::from("m25"))
         );
 
-        // original name priority == 0
-        let results = vec![
-            Name::new(
-                String::from("Ontario Route 101"),
-                1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Highway 101"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Route 101"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("ON 101"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-        ];
         assert_eq!(
-            syn_ca_hwy(&Name::new(String::from("101"), 0, None, &context), &context),
-            results
+            is_routish(
+                &Name::new(String::from("C40 Rd"), 0, None, &context),
+                &context.country
+            ),
+            None
         );
+
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("ON-101"), 0, None, &context),
-                &context
+            is_routish(
+                &Name::new(String::from("321"), 0, None, &context),
+                &context.country
             ),
-            results
+            Some(String::from("321"))
         );
+
+        // N & R roads are IE only
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("Kings's Highway 101"), 0, None, &context),
-                &context
+            is_routish(
+                &Name::new(String::from("N7"), 0, None, &context),
+                &context.country
             ),
-            results
+            None
         );
+
+        // Country codes are matched regardless of case
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("Highway 101"), 0, None, &context),
-                &context
-            ),
-            results
+            is_routish(&Name::new(String::from("N7"), 0, None, &context), "ie"),
+            Some(String::from("n7"))
         );
+
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("Route 101"), 0, None, &context),
-                &context
-            ),
-            results
+            is_routish(&Name::new(String::from("R120"), 0, None, &context), "IE"),
+            Some(String::from("r120"))
         );
+
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("Ontario Highway 101"), 0, None, &context),
-                &context
-            ),
-            results
+            is_routish(&Name::new(String::from("M50"), 0, None, &context), "IE"),
+            Some(String::from("m50"))
         );
 
-        // Highway preferencing proveninces
+        // A & B roads are GB only
+        assert_eq!(
+            is_routish(&Name::new(String::from("A40"), 0, None, &context), "IE"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_syn_de_street() {
         let context = Context::new(
-            String::from("ca"),
-            Some(String::from("nb")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            String::from("de"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
-        let results = vec![
-            Name::new(
-                String::from("New Brunswick Highway 101"),
-                1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Highway 101"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Route 101"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("NB 101"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-        ];
+
         assert_eq!(
-            syn_ca_hwy(&Name::new(String::from("101"), 0, None, &context), &context),
-            results
+            syn_de_street(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
         );
+
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("NB-101"), 0, None, &context),
+            syn_de_street(
+                &Name::new(String::from("Hauptstraße"), 0, None, &context),
                 &context
             ),
-            results
+            vec![
+                Name::new(
+                    String::from("Hauptstr."),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Haupt Straße"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
         );
+
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("Kings's Highway 101"), 0, None, &context),
+            syn_de_street(
+                &Name::new(String::from("Haupt Str."), 0, None, &context),
                 &context
             ),
-            results
+            vec![
+                Name::new(
+                    String::from("Hauptstraße"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Hauptstr."),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Haupt Straße"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
         );
+
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("Highway 101"), 0, None, &context),
+            syn_de_street(
+                &Name::new(String::from("Marktplatz"), -1, None, &context),
                 &context
             ),
-            results
+            vec![
+                Name::new(
+                    String::from("Marktpl."),
+                    -2,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Markt Platz"),
+                    -2,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
         );
+
+        // adjectival & multi word names are not joined
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("Route 101"), 0, None, &context),
+            syn_de_street(
+                &Name::new(String::from("Berliner Straße"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
         );
         assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("New Brunswick Highway 101"), 0, None, &context),
+            syn_de_street(
+                &Name::new(String::from("Straße des 17. Juni"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
         );
 
-        // original name priority < 0
-        let results = vec![
-            Name::new(
-                String::from("New Brunswick Highway 101"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Highway 101"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Route 101"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("NB 101"),
-                -3,
-                Some(Source::Generated),
-                &context,
-            ),
-        ];
-        assert_eq!(
-            syn_ca_hwy(
-                &Name::new(String::from("101"), -1, None, &context),
-                &context
-            ),
-            results
+        let context = Context::new(
+            String::from("ch"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
-        // original name priority > 0
-        let results = vec![
-            Name::new(
-                String::from("New Brunswick Highway 101"),
-                2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Highway 101"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Route 101"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("NB 101"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-        ];
         assert_eq!(
-            syn_ca_hwy(&Name::new(String::from("101"), 1, None, &context), &context),
-            results
+            syn_de_street(
+                &Name::new(String::from("Bahnhofstr."), 0, None, &context),
+                &context
+            ),
+            vec![
+                Name::new(
+                    String::from("Bahnhofstrasse"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Bahnhof Strasse"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
         );
     }
 
     #[test]
-    fn test_syn_us_hwy() {
+    fn test_syn_de_street_tokens() {
+        // Street types tokenized to a common canonical must not drop every synonym
+        let mut tokens: HashMap<String, ParsedToken> = HashMap::new();
+        for token in &["strasse", "str"] {
+            tokens.insert(
+                String::from(*token),
+                ParsedToken::new(String::from("str"), None),
+            );
+        }
+
         let context = Context::new(
-            String::from("us"),
+            String::from("de"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(tokens, HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        let syns = syn_de_street(
+            &Name::new(String::from("Hauptstraße"), 0, None, &context),
+            &context,
         );
 
         assert_eq!(
-            syn_us_hwy(&Name::new(String::from(""), 0, None, &context), &context),
-            vec![]
+            syns.iter()
+                .map(|syn| syn.tokenized_string())
+                .collect::<Vec<String>>(),
+            vec![String::from("hauptstr"), String::from("haupt str")]
         );
 
-        // original name priority == 0
-        let results = vec![
-            Name::new(
-                String::from("US Route 81"),
-                1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(String::from("US 81"), -1, Some(Source::Generated), &context),
-            Name::new(
-                String::from("US Highway 81"),
-                -1,
-                Some(Source::Generated),
-                &context,
+        // The split form tokenizes as the abbreviated original & is dropped
+        assert_eq!(
+            syn_de_street(
+                &Name::new(String::from("Haupt Str."), 0, None, &context),
+                &context
+            )
+            .iter()
+            .map(|syn| syn.display.clone())
+            .collect::<Vec<String>>(),
+            vec![String::from("Hauptstraße"), String::from("Hauptstr.")]
+        );
+    }
+
+    #[test]
+    fn test_syn_de_eszett() {
+        let context = Context::new(
+            String::from("de"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_de_eszett(
+                &Name::new(String::from("Große Gasse"), 0, None, &context),
+                &context
             ),
-            Name::new(
-                String::from("United States Route 81"),
+            vec![Name::new(
+                String::from("Grosse Gasse"),
                 -1,
                 Some(Source::Generated),
-                &context,
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_de_eszett(
+                &Name::new(String::from("Strasse des 17. Juni"), 0, None, &context),
+                &context
             ),
-            Name::new(
-                String::from("United States Highway 81"),
+            vec![Name::new(
+                String::from("Straße Des 17. Juni"),
                 -1,
                 Some(Source::Generated),
-                &context,
-            ),
-        ];
+                &context
+            )]
+        );
 
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("us-81"), 0, None, &context),
+            syn_de_eszett(
+                &Name::new(String::from("Am Markt"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
         );
+
+        // Both spellings are kept once deduped, the original keeping its display
+        let original = Name::new(String::from("Große Gasse"), 0, None, &context);
+        let mut syns = syn_de_eszett(&original, &context);
+
+        let mut names = Names {
+            names: vec![original],
+        };
+        names.names.append(&mut syns);
+        names.dedupe(&context.country);
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("US 81"), 0, None, &context),
-                &context
-            ),
-            results
+            names
+                .names
+                .iter()
+                .map(|name| name.display.clone())
+                .collect::<Vec<String>>(),
+            vec![String::from("Große Gasse"), String::from("Grosse Gasse")]
         );
+
+        // Swiss names do not use ß
+        let context = Context::new(
+            String::from("ch"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("U.S. Route 81"), 0, None, &context),
+            syn_de_eszett(
+                &Name::new(String::from("Bahnhofstrasse"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_syn_de_sankt() {
+        let context = Context::new(
+            String::from("at"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("US Route 81"), 0, None, &context),
+            syn_de_sankt(
+                &Name::new(String::from("Sankt-Georg-Platz"), 0, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("St.-Georg-Platz"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("US Rte 81"), 0, None, &context),
+            syn_de_sankt(
+                &Name::new(String::from("St. Veiter Straße"), 0, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("Sankt Veiter Straße"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("US Hwy 81"), 0, None, &context),
+            syn_de_sankt(
+                &Name::new(String::from("Stiftgasse"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_syn_fr_particle() {
+        let context = Context::new(
+            String::from("fr"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("US Highway 81"), 0, None, &context),
-                &context
-            ),
-            results
+            syn_fr_particle(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("United States 81"), 0, None, &context),
+            syn_fr_particle(
+                &Name::new(String::from("Rue de la Paix"), 0, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("Rue Paix"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("United States Route 81"), 0, None, &context),
+            syn_fr_particle(
+                &Name::new(String::from("Avenue du Général Leclerc"), 0, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("Avenue Général Leclerc"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("United States Highway 81"), 0, None, &context),
+            syn_fr_particle(
+                &Name::new(String::from("Place de l'Église"), -1, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("Place Église"),
+                -2,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("United States Hwy 81"), 0, None, &context),
+            syn_fr_particle(
+                &Name::new(String::from("Boulevard Haussmann"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
         );
+    }
 
-        // original name priority < 0
-        let results = vec![
-            Name::new(
-                String::from("US Route 81"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(String::from("US 81"), -2, Some(Source::Generated), &context),
-            Name::new(
-                String::from("US Highway 81"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("United States Route 81"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("United States Highway 81"),
-                -2,
-                Some(Source::Generated),
+    #[test]
+    fn test_syn_fr_saint() {
+        let context = Context::new(
+            String::from("be"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        let displays = |display: &str| -> Vec<String> {
+            syn_fr_saint(
+                &Name::new(String::from(display), 0, None, &context),
                 &context,
-            ),
-        ];
+            )
+            .into_iter()
+            .map(|syn| syn.display)
+            .collect()
+        };
 
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("US Route 81"), -1, None, &context),
+            syn_fr_saint(
+                &Name::new(String::from("Rue Saint-Jean"), 0, None, &context),
                 &context
             ),
-            results
+            vec![
+                Name::new(
+                    String::from("Rue St-Jean"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Rue St Jean"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Rue Saint Jean"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
         );
 
-        // original name priority > 0
-        let results = vec![
-            Name::new(
-                String::from("US Route 81"),
-                2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(String::from("US 81"), -1, Some(Source::Generated), &context),
-            Name::new(
-                String::from("US Highway 81"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("United States Route 81"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("United States Highway 81"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-        ];
+        // hyphen => space
+        assert_eq!(
+            displays("Rue St-Jean"),
+            vec!["Rue Saint-Jean", "Rue Saint Jean", "Rue St Jean"]
+        );
+
+        // space => hyphen
+        assert_eq!(
+            displays("Rue Saint Jean"),
+            vec!["Rue St Jean", "Rue St-Jean", "Rue Saint-Jean"]
+        );
+        assert_eq!(
+            displays("Rue St Jean"),
+            vec!["Rue Saint Jean", "Rue Saint-Jean", "Rue St-Jean"]
+        );
+
+        assert_eq!(
+            displays("Rue Ste-Catherine"),
+            vec![
+                "Rue Sainte-Catherine",
+                "Rue Sainte Catherine",
+                "Rue Ste Catherine"
+            ]
+        );
 
         assert_eq!(
-            syn_us_hwy(
-                &Name::new(String::from("US Route 81"), 1, None, &context),
-                &context
-            ),
-            results
+            displays("St Germain des Prés"),
+            vec![
+                "Saint Germain des Prés",
+                "Saint-Germain des Prés",
+                "St-Germain des Prés"
+            ]
         );
+
+        assert_eq!(displays("Rue Stalingrad"), Vec::<String>::new());
     }
 
     #[test]
-    fn test_syn_state_hwy() {
+    fn test_syn_gb_road() {
         let context = Context::new(
-            String::from("us"),
-            Some(String::from("PA")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            String::from("gb"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            syn_state_hwy(&Name::new(String::from(""), 0, None, &context), &context),
+            syn_gb_road(&Name::new(String::from(""), 0, None, &context), &context),
             vec![]
         );
 
-        // original name priority == 0
-        let results = vec![
-            Name::new(
-                String::from("Pennsylvania Highway 123"),
-                1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("PA 123 Highway"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("PA 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Highway 123"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("SR 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("State Highway 123"),
+        assert_eq!(
+            syn_gb_road(&Name::new(String::from("A40"), 0, None, &context), &context),
+            vec![Name::new(
+                String::from("A40 Road"),
                 -1,
                 Some(Source::Generated),
-                &context,
+                &context
+            )]
+        );
+
+        assert_eq!(
+            syn_gb_road(
+                &Name::new(String::from("A40 Road"), 0, None, &context),
+                &context
             ),
-            Name::new(
-                String::from("State Route 123"),
+            vec![Name::new(
+                String::from("A40"),
                 -1,
                 Some(Source::Generated),
-                &context,
-            ),
-        ];
+                &context
+            )]
+        );
 
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("St Hwy 123"), 0, None, &context),
+            syn_gb_road(
+                &Name::new(String::from("m25"), -1, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("M25 Motorway"),
+                -2,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("St Rte 123"), 0, None, &context),
+            syn_gb_road(
+                &Name::new(String::from("M25 Motorway"), 0, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("M25"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
+        // N roads are only numbered in Ireland
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("State Highway 123"), 0, None, &context),
-                &context
-            ),
-            results
+            syn_gb_road(&Name::new(String::from("N7"), 0, None, &context), &context),
+            vec![]
         );
+
+        let context = Context::new(
+            String::from("ie"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("Highway 123"), 0, None, &context),
+            syn_gb_road(&Name::new(String::from("N7"), 0, None, &context), &context),
+            vec![Name::new(
+                String::from("N7 Road"),
+                -1,
+                Some(Source::Generated),
                 &context
-            ),
-            results
+            )]
         );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("Hwy 123"), 0, None, &context),
+            syn_gb_road(
+                &Name::new(String::from("B1234"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_syn_au_hwy() {
+        let context = Context::new(
+            String::from("au"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("Pennsylvania Highway 123"), 0, None, &context),
-                &context
-            ),
-            results
+            syn_au_hwy(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
         );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("Pennsylvania Route 123"), 0, None, &context),
+            syn_au_hwy(
+                &Name::new(String::from("National Route 1"), 0, None, &context),
                 &context
             ),
-            results
+            vec![
+                Name::new(
+                    String::from("National Highway 1"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Highway 1"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
         );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("PA 123"), 0, None, &context),
+            syn_au_hwy(
+                &Name::new(String::from("State Route 12"), -1, None, &context),
                 &context
             ),
-            results
+            vec![Name::new(
+                String::from("Highway 12"),
+                -2,
+                Some(Source::Generated),
+                &context
+            )]
         );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("PA-123"), 0, None, &context),
+            syn_au_hwy(&Name::new(String::from("M1"), 0, None, &context), &context),
+            vec![Name::new(
+                String::from("Route M1"),
+                -1,
+                Some(Source::Generated),
                 &context
-            ),
-            results
+            )]
         );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("US-PA-123"), 0, None, &context),
+            syn_au_hwy(
+                &Name::new(String::from("Route A32"), 0, None, &context),
                 &context
             ),
-            results
-        );
-
-        // original name priority < 0
-        let results = vec![
-            Name::new(
-                String::from("Pennsylvania Highway 123"),
+            vec![Name::new(
+                String::from("A32"),
                 -1,
                 Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("PA 123 Highway"),
-                -3,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("PA 123"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Highway 123"),
-                -3,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("SR 123"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("State Highway 123"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("State Route 123"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-        ];
+                &context
+            )]
+        );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("Pennsylvania Highway 123"), -1, None, &context),
+            syn_au_hwy(
+                &Name::new(String::from("George Street"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
         );
+    }
 
-        // original name priority > 0
-        let results = vec![
-            Name::new(
-                String::from("Pennsylvania Highway 123"),
-                2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("PA 123 Highway"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("PA 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("Highway 123"),
-                -2,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("SR 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("State Highway 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
-            ),
-            Name::new(
-                String::from("State Route 123"),
-                -1,
-                Some(Source::Generated),
-                &context,
+    #[test]
+    fn test_syn_nz_hwy() {
+        let context = Context::new(
+            String::from("nz"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            syn_nz_hwy(&Name::new(String::from("SH1"), 0, None, &context), &context),
+            vec![
+                Name::new(
+                    String::from("State Highway 1"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Highway 1"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
+        );
+
+        assert_eq!(
+            syn_nz_hwy(
+                &Name::new(String::from("State Highway 16"), 0, None, &context),
+                &context
             ),
-        ];
+            vec![
+                Name::new(String::from("SH16"), -1, Some(Source::Generated), &context),
+                Name::new(
+                    String::from("Highway 16"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
+        );
+
         assert_eq!(
-            syn_state_hwy(
-                &Name::new(String::from("Pennsylvania Highway 123"), 1, None, &context),
+            syn_nz_hwy(
+                &Name::new(String::from("Queen Street"), 0, None, &context),
                 &context
             ),
-            results
+            vec![]
         );
     }
 
     #[test]
-    fn test_syn_number_suffix() {
+    fn test_syn_es_street() {
         let context = Context::new(
-            String::from("us"),
+            String::from("co"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            syn_number_suffix(
-                &Name::new(String::from("1st Avenue"), 0, None, &context),
-                &context
-            ),
-            Vec::new()
+            syn_es_street(&Name::new(String::from(""), 0, None, &context), &context),
+            vec![]
         );
 
         assert_eq!(
-            syn_number_suffix(
-                &Name::new(String::from("1 Avenue"), 0, None, &context),
+            syn_es_street(
+                &Name::new(String::from("Calle 5"), 0, None, &context),
                 &context
             ),
             vec![Name::new(
-                String::from("1st Avenue"),
+                String::from("5"),
                 -1,
                 Some(Source::Generated),
                 &context
@@ -2656,12 +4306,9 @@ mod tests {
         );
 
         assert_eq!(
-            syn_number_suffix(
-                &Name::new(String::from("2 Avenue"), 0, None, &context),
-                &context
-            ),
+            syn_es_street(&Name::new(String::from("5"), 0, None, &context), &context),
             vec![Name::new(
-                String::from("2nd Avenue"),
+                String::from("Calle 5"),
                 -1,
                 Some(Source::Generated),
                 &context
@@ -2669,38 +4316,93 @@ mod tests {
         );
 
         assert_eq!(
-            syn_number_suffix(
-                &Name::new(String::from("3 Street"), 0, None, &context),
+            syn_es_street(
+                &Name::new(String::from("Cl 26"), -1, None, &context),
                 &context
             ),
-            vec![Name::new(
-                String::from("3rd Street"),
-                -1,
-                Some(Source::Generated),
+            vec![
+                Name::new(
+                    String::from("Calle 26"),
+                    -2,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(String::from("26"), -2, Some(Source::Generated), &context),
+            ]
+        );
+
+        assert_eq!(
+            syn_es_street(
+                &Name::new(String::from("Carrera 7"), 0, None, &context),
                 &context
-            )]
+            ),
+            vec![
+                Name::new(String::from("Cra 7"), -1, Some(Source::Generated), &context),
+                Name::new(String::from("Kr 7"), -1, Some(Source::Generated), &context),
+            ]
         );
 
         assert_eq!(
-            syn_number_suffix(
-                &Name::new(String::from("4 Street"), 0, None, &context),
+            syn_es_street(
+                &Name::new(String::from("Kr. 7"), 0, None, &context),
                 &context
             ),
-            vec![Name::new(
-                String::from("4th Street"),
-                -1,
-                Some(Source::Generated),
+            vec![
+                Name::new(
+                    String::from("Carrera 7"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(String::from("Cra 7"), -1, Some(Source::Generated), &context),
+            ]
+        );
+
+        assert_eq!(
+            syn_es_street(
+                &Name::new(String::from("Avenida Juárez"), 0, None, &context),
                 &context
-            )]
+            ),
+            vec![
+                Name::new(
+                    String::from("Av Juárez"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("Avda Juárez"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
         );
 
         assert_eq!(
-            syn_number_suffix(
-                &Name::new(String::from("20 Street"), 0, None, &context),
+            syn_es_street(
+                &Name::new(String::from("Plaza Mayor"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+
+        // Only numbered calles are left bare
+        assert_eq!(
+            syn_es_street(
+                &Name::new(String::from("Calle Mayor"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+
+        assert_eq!(
+            syn_es_street(
+                &Name::new(String::from("C/ Mayor"), 0, None, &context),
                 &context
             ),
             vec![Name::new(
-                String::from("20th Street"),
+                String::from("Calle Mayor"),
                 -1,
                 Some(Source::Generated),
                 &context
@@ -2708,34 +4410,42 @@ mod tests {
         );
 
         assert_eq!(
-            syn_number_suffix(
-                &Name::new(String::from("21 Street"), 0, None, &context),
+            syn_es_street(
+                &Name::new(String::from("Calle 5ª"), 0, None, &context),
                 &context
             ),
             vec![Name::new(
-                String::from("21st Street"),
+                String::from("5ª"),
                 -1,
                 Some(Source::Generated),
                 &context
             )]
         );
+
+        assert_eq!(
+            syn_es_street(
+                &Name::new(String::from("Mayor"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
     }
 
     #[test]
-    fn test_syn_written_numeric() {
+    fn test_syn_es_honorific() {
         let context = Context::new(
-            String::from("us"),
+            String::from("ar"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            syn_written_numeric(
-                &Name::new(String::from("Twenty-third Avenue NW"), 0, None, &context),
+            syn_es_honorific(
+                &Name::new(String::from("Avenida General Paz"), 0, None, &context),
                 &context
             ),
             vec![Name::new(
-                String::from("23rd Avenue NW"),
+                String::from("Avenida Paz"),
                 -1,
                 Some(Source::Generated),
                 &context
@@ -2743,12 +4453,12 @@ mod tests {
         );
 
         assert_eq!(
-            syn_written_numeric(
-                &Name::new(String::from("North twenty-Third Avenue"), 0, None, &context),
+            syn_es_honorific(
+                &Name::new(String::from("Presidente Masaryk"), 0, None, &context),
                 &context
             ),
             vec![Name::new(
-                String::from("North 23rd Avenue"),
+                String::from("Masaryk"),
                 -1,
                 Some(Source::Generated),
                 &context
@@ -2756,147 +4466,182 @@ mod tests {
         );
 
         assert_eq!(
-            syn_written_numeric(
-                &Name::new(String::from("TWENTY-THIRD Avenue"), 0, None, &context),
+            syn_es_honorific(
+                &Name::new(String::from("Calle Gral. San Martín"), 0, None, &context),
                 &context
             ),
             vec![Name::new(
-                String::from("23rd Avenue"),
+                String::from("Calle San Martín"),
                 -1,
                 Some(Source::Generated),
                 &context
             )]
         );
-    }
-
-    #[test]
-    fn test_str_remove_octo() {
-        assert_eq!(
-            str_remove_octo(&String::from("Highway #12 West")),
-            String::from("Highway 12 West")
-        );
 
         assert_eq!(
-            str_remove_octo(&String::from("RTe #1")),
-            String::from("RTe 1")
+            syn_es_honorific(
+                &Name::new(String::from("Hospital General"), 0, None, &context),
+                &context
+            ),
+            vec![]
         );
     }
 
     #[test]
-    fn test_is_numbered() {
+    fn test_syn_written_ordinal() {
         let context = Context::new(
             String::from("us"),
-            Some(String::from("PA")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-        );
-
-        assert_eq!(
-            is_numbered(&Name::new(String::from("main st"), 0, None, &context)),
-            None
-        );
-
-        assert_eq!(
-            is_numbered(&Name::new(String::from("1st st"), 0, None, &context)),
-            Some(String::from("1st"))
-        );
-
-        assert_eq!(
-            is_numbered(&Name::new(String::from("2nd st"), 0, None, &context)),
-            Some(String::from("2nd"))
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_numbered(&Name::new(String::from("west 2nd st"), 0, None, &context)),
-            Some(String::from("2nd"))
+            syn_written_ordinal(
+                &Name::new(String::from("First Avenue"), 0, None, &context),
+                &context
+            ),
+            vec![]
         );
 
-        assert_eq!(
-            is_numbered(&Name::new(String::from("3rd st"), 0, None, &context)),
-            Some(String::from("3rd"))
+        let context = Context::new(
+            String::from("fr"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_numbered(&Name::new(String::from("4th st"), 0, None, &context)),
-            Some(String::from("4th"))
+            syn_written_ordinal(
+                &Name::new(String::from("Première Avenue"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("1re Avenue"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
 
-        assert_eq!(
-            is_numbered(&Name::new(String::from("11th ave"), 0, None, &context)),
-            Some(String::from("11th"))
+        let context = Context::new(
+            String::from("fr"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_numbered(&Name::new(String::from("12th ave"), 0, None, &context)),
-            Some(String::from("12th"))
+            syn_written_ordinal(
+                &Name::new(String::from("Troisième Avenue"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("3e Avenue"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
 
-        assert_eq!(
-            is_numbered(&Name::new(String::from("21st av"), 0, None, &context)),
-            Some(String::from("21st"))
+        let context = Context::new(
+            String::from("de"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_numbered(&Name::new(String::from("32nd av"), 0, None, &context)),
-            Some(String::from("32nd"))
+            syn_written_ordinal(
+                &Name::new(String::from("Zweite Straße"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("2. Straße"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
 
-        assert_eq!(
-            is_numbered(&Name::new(String::from("45th av"), 0, None, &context)),
-            Some(String::from("45th"))
+        let context = Context::new(
+            String::from("es"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_numbered(&Name::new(String::from("351235th av"), 0, None, &context)),
-            Some(String::from("351235th"))
+            syn_written_ordinal(
+                &Name::new(String::from("Calle Primera"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("Calle 1ª"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
-    }
 
-    #[test]
-    fn test_is_routish() {
         let context = Context::new(
-            String::from("us"),
-            Some(String::from("PA")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            String::from("it"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_routish(&Name::new(String::from("main st"), 0, None, &context)),
-            None
+            syn_written_ordinal(
+                &Name::new(String::from("Via Quarto"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("Via 4º"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
 
-        assert_eq!(
-            is_routish(&Name::new(String::from("1st st"), 0, None, &context)),
-            None
+        let context = Context::new(
+            String::from("nl"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_routish(&Name::new(String::from("351235th av"), 0, None, &context)),
-            None
+            syn_written_ordinal(
+                &Name::new(String::from("Eerste Weg"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("1e Weg"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
 
-        assert_eq!(
-            is_routish(&Name::new(String::from("NC 124"), 0, None, &context)),
-            Some(String::from("124"))
+        let context = Context::new(
+            String::from("ru"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
-            is_routish(&Name::new(
-                String::from("US Route 50 East"),
-                0,
-                None,
+            syn_written_ordinal(
+                &Name::new(String::from("Первая Улица"), 0, None, &context),
                 &context
-            )),
-            Some(String::from("50"))
-        );
-
-        assert_eq!(
-            is_routish(&Name::new(String::from("321"), 0, None, &context)),
-            Some(String::from("321"))
+            ),
+            vec![Name::new(
+                String::from("1-я Улица"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
         );
 
         assert_eq!(
-            is_routish(&Name::new(String::from("124 NC"), 0, None, &context)),
-            Some(String::from("124"))
+            syn_written_ordinal(
+                &Name::new(String::from("Hauptstraße"), 0, None, &context),
+                &context
+            ),
+            vec![]
         );
     }
 }
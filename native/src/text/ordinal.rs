@@ -0,0 +1,377 @@
+use super::diacritics;
+use regex::Regex;
+
+///
+/// Languages with their own ordinal notation
+///
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Language {
+    En,
+    Fr,
+    De,
+    Es,
+    Pt,
+    It,
+    Nl,
+    Ru,
+}
+
+impl Language {
+    ///
+    /// Ordinal notation used by a given ISO 3166-1 country code
+    ///
+    pub fn from_country(country: &str) -> Option<Self> {
+        match country.to_uppercase().as_str() {
+            "US" | "CA" | "GB" | "IE" | "AU" | "NZ" => Some(Language::En),
+            "FR" | "BE" | "LU" => Some(Language::Fr),
+            "DE" | "AT" | "CH" => Some(Language::De),
+            "ES" | "MX" | "AR" | "CO" | "CL" | "PE" => Some(Language::Es),
+            "PT" | "BR" => Some(Language::Pt),
+            "IT" => Some(Language::It),
+            "NL" => Some(Language::Nl),
+            "RU" => Some(Language::Ru),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+///
+/// Numeric ordinal form of a number in a given language
+/// ie: 1st, 1er/1re, 1., 1º/1ª, 1e, 1-й/1-я
+///
+pub fn numeric(num: i64, language: Language, gender: Gender) -> String {
+    let suffix = match language {
+        Language::En => {
+            if (num % 100) >= 10 && (num % 100) <= 20 {
+                "th"
+            } else if (num % 10) == 1 {
+                "st"
+            } else if (num % 10) == 2 {
+                "nd"
+            } else if (num % 10) == 3 {
+                "rd"
+            } else {
+                "th"
+            }
+        }
+        Language::Fr => match (num, gender) {
+            (1, Gender::Feminine) => "re",
+            (1, _) => "er",
+            _ => "e",
+        },
+        Language::De => ".",
+        Language::Es | Language::Pt | Language::It => match gender {
+            Gender::Feminine => "ª",
+            _ => "º",
+        },
+        Language::Nl => "e",
+        Language::Ru => match gender {
+            Gender::Feminine => "-я",
+            Gender::Masculine => "-й",
+            Gender::Neuter => "-е",
+        },
+    };
+
+    format!("{}{}", num, suffix)
+}
+
+///
+/// Parse a single written ordinal word, returning its number & gender
+/// ie: "Première" => (1, Feminine), "Zweite" => (2, Feminine)
+///
+pub fn written(word: &str, language: Language) -> Option<(i64, Gender)> {
+    lazy_static! {
+        static ref RU_ENDING: Regex =
+            Regex::new(r"^(?P<stem>\w+?)(?P<end>ая|ья|ый|ий|ой|ое|ье)$").unwrap();
+    }
+
+    // Cyrillic is left as is, as diacritics folds some of its letters
+    let word = match language {
+        Language::Ru => word.to_lowercase(),
+        _ => diacritics(&word.to_lowercase()),
+    };
+
+    match language {
+        Language::En => None,
+        Language::Fr => {
+            let num = match word.as_str() {
+                "premier" | "premiere" => 1,
+                "deuxieme" => 2,
+                "troisieme" => 3,
+                "quatrieme" => 4,
+                "cinquieme" => 5,
+                "sixieme" => 6,
+                "septieme" => 7,
+                "huitieme" => 8,
+                "neuvieme" => 9,
+                "dixieme" => 10,
+                _ => return None,
+            };
+
+            let gender = match word.as_str() {
+                "premiere" => Gender::Feminine,
+                _ => Gender::Masculine,
+            };
+
+            Some((num, gender))
+        }
+        Language::De => {
+            // erste, erster, ersten, erstes...
+            let stem =
+                word.trim_end_matches(|c: char| c == 'r' || c == 'n' || c == 's' || c == 'm');
+
+            let num = match stem {
+                "erste" => 1,
+                "zweite" => 2,
+                "dritte" => 3,
+                "vierte" => 4,
+                "funfte" => 5,
+                "sechste" => 6,
+                "siebte" => 7,
+                "achte" => 8,
+                "neunte" => 9,
+                "zehnte" => 10,
+                _ => return None,
+            };
+
+            Some((num, Gender::Feminine))
+        }
+        Language::Es | Language::Pt | Language::It => {
+            let gender = if word.ends_with('a') {
+                Gender::Feminine
+            } else {
+                Gender::Masculine
+            };
+
+            let stem = word.trim_end_matches(|c: char| c == 'a' || c == 'o');
+
+            let num = match (language, stem) {
+                (Language::Es, "primer") | (Language::Pt, "primeir") | (Language::It, "prim") => 1,
+                (Language::Es, "segund") | (Language::Pt, "segund") | (Language::It, "second") => 2,
+                (Language::Es, "tercer") | (Language::Pt, "terceir") | (Language::It, "terz") => 3,
+                (Language::Es, "cuart") | (Language::Pt, "quart") | (Language::It, "quart") => 4,
+                (Language::Es, "quint") | (Language::Pt, "quint") | (Language::It, "quint") => 5,
+                (Language::Es, "sext") | (Language::Pt, "sext") | (Language::It, "sest") => 6,
+                (Language::Es, "septim") | (Language::Pt, "setim") | (Language::It, "settim") => 7,
+                (Language::Es, "octav") | (Language::Pt, "oitav") | (Language::It, "ottav") => 8,
+                (Language::Es, "noven") | (Language::Pt, "non") | (Language::It, "non") => 9,
+                (Language::Es, "decim") | (Language::Pt, "decim") | (Language::It, "decim") => 10,
+                _ => return None,
+            };
+
+            Some((num, gender))
+        }
+        Language::Nl => {
+            let num = match word.as_str() {
+                "eerste" => 1,
+                "tweede" => 2,
+                "derde" => 3,
+                "vierde" => 4,
+                "vijfde" => 5,
+                "zesde" => 6,
+                "zevende" => 7,
+                "achtste" => 8,
+                "negende" => 9,
+                "tiende" => 10,
+                _ => return None,
+            };
+
+            Some((num, Gender::Neuter))
+        }
+        Language::Ru => {
+            let capture = RU_ENDING.captures(word.as_str())?;
+
+            let num = match &capture["stem"] {
+                "перв" => 1,
+                "втор" => 2,
+                "трет" => 3,
+                "четверт" | "четвёрт" => 4,
+                "пят" => 5,
+                "шест" => 6,
+                "седьм" => 7,
+                "восьм" => 8,
+                "девят" => 9,
+                "десят" => 10,
+                _ => return None,
+            };
+
+            let gender = match &capture["end"] {
+                "ая" | "ья" => Gender::Feminine,
+                "ое" | "ье" => Gender::Neuter,
+                _ => Gender::Masculine,
+            };
+
+            Some((num, gender))
+        }
+    }
+}
+
+///
+/// Parse a numeric ordinal in the notation of a given language, from either
+/// a single token or the display form of a name, returning the ordinal as written
+/// ie: 1st, 1re, 1., 1ª, 1-я
+///
+pub fn parse(tokens: &Vec<String>, display: &String, language: Language) -> Option<String> {
+    lazy_static! {
+        static ref EN: Regex = Regex::new(r"^([0-9]+)?(1st|2nd|3rd|[0-9]th)$").unwrap();
+        static ref FR: Regex = Regex::new(r"^[0-9]+(er|re|ere|e|eme)$").unwrap();
+        static ref NL: Regex = Regex::new(r"^[0-9]+(e|de|ste)$").unwrap();
+
+        // ES/PT/IT 1º/1ª & ES 1ro/2da
+        static ref ES: Regex = Regex::new(r"^[0-9]+([ªº°]|[rdtmvn][ao])$").unwrap();
+        static ref PT_IT: Regex = Regex::new(r"^[0-9]+[ªº°]$").unwrap();
+
+        // DE 1. & RU 1-я/1-й, whose punctuation does not survive tokenization
+        static ref DE: Regex = Regex::new(r"(?:^|\s)(?P<ord>[0-9]+\.)(?:\s|$)").unwrap();
+        static ref RU: Regex = Regex::new(
+            r"(?i)(?:^|\s)(?P<ord>[0-9]+-?(я|й|ая|ий|ый|ой|е|ое|го))(?:\s|$)"
+        )
+        .unwrap();
+    }
+
+    let token: &Regex = match language {
+        Language::En => &EN,
+        Language::Fr => &FR,
+        Language::Nl => &NL,
+        Language::Es => &ES,
+        Language::Pt | Language::It => &PT_IT,
+        Language::De => {
+            return DE
+                .captures(display)
+                .map(|capture| capture["ord"].to_string());
+        }
+        Language::Ru => {
+            return RU
+                .captures(display)
+                .map(|capture| capture["ord"].to_string());
+        }
+    };
+
+    tokens.iter().find(|tk| token.is_match(tk)).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric() {
+        assert_eq!(numeric(1, Language::En, Gender::Neuter), "1st");
+        assert_eq!(numeric(12, Language::En, Gender::Neuter), "12th");
+        assert_eq!(numeric(1, Language::Fr, Gender::Feminine), "1re");
+        assert_eq!(numeric(1, Language::Fr, Gender::Masculine), "1er");
+        assert_eq!(numeric(2, Language::Fr, Gender::Feminine), "2e");
+        assert_eq!(numeric(17, Language::De, Gender::Feminine), "17.");
+        assert_eq!(numeric(5, Language::Es, Gender::Masculine), "5º");
+        assert_eq!(numeric(1, Language::Pt, Gender::Feminine), "1ª");
+        assert_eq!(numeric(3, Language::Nl, Gender::Neuter), "3e");
+        assert_eq!(numeric(1, Language::Ru, Gender::Feminine), "1-я");
+        assert_eq!(numeric(2, Language::Ru, Gender::Masculine), "2-й");
+    }
+
+    #[test]
+    fn test_written() {
+        assert_eq!(written("Main", Language::Fr), None);
+        assert_eq!(written("First", Language::En), None);
+        assert_eq!(
+            written("Première", Language::Fr),
+            Some((1, Gender::Feminine))
+        );
+        assert_eq!(
+            written("Troisième", Language::Fr),
+            Some((3, Gender::Masculine))
+        );
+        assert_eq!(written("Ersten", Language::De), Some((1, Gender::Feminine)));
+        assert_eq!(written("Fünfte", Language::De), Some((5, Gender::Feminine)));
+        assert_eq!(
+            written("Primera", Language::Es),
+            Some((1, Gender::Feminine))
+        );
+        assert_eq!(
+            written("Tercero", Language::Es),
+            Some((3, Gender::Masculine))
+        );
+        assert_eq!(
+            written("Sétimo", Language::Pt),
+            Some((7, Gender::Masculine))
+        );
+        assert_eq!(
+            written("Seconda", Language::It),
+            Some((2, Gender::Feminine))
+        );
+        assert_eq!(written("Tweede", Language::Nl), Some((2, Gender::Neuter)));
+        assert_eq!(written("Первая", Language::Ru), Some((1, Gender::Feminine)));
+        assert_eq!(
+            written("Третий", Language::Ru),
+            Some((3, Gender::Masculine))
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse_str = |text: &str, language: Language| {
+            let text = String::from(text);
+            let tokens: Vec<String> = text
+                .to_lowercase()
+                .split(|c: char| c.is_whitespace() || c == '.' || c == '-')
+                .filter(|token| token.len() > 0)
+                .map(|token| String::from(token))
+                .collect();
+
+            parse(&tokens, &text, language)
+        };
+
+        assert_eq!(parse_str("Main St", Language::En), None);
+        assert_eq!(
+            parse_str("21st Av", Language::En),
+            Some(String::from("21st"))
+        );
+        assert_eq!(
+            parse_str("1re Avenue", Language::Fr),
+            Some(String::from("1re"))
+        );
+        assert_eq!(parse_str("2e Rue", Language::Fr), Some(String::from("2e")));
+        assert_eq!(parse_str("Calle 5", Language::Es), None);
+        assert_eq!(
+            parse_str("Calle 1ª", Language::Es),
+            Some(String::from("1ª"))
+        );
+        assert_eq!(
+            parse_str("2da Calle", Language::Es),
+            Some(String::from("2da"))
+        );
+        assert_eq!(
+            parse_str("3de Laan", Language::Nl),
+            Some(String::from("3de"))
+        );
+        assert_eq!(
+            parse_str("1. Straße", Language::De),
+            Some(String::from("1."))
+        );
+        assert_eq!(
+            parse_str("1-я улица", Language::Ru),
+            Some(String::from("1-я"))
+        );
+
+        // Only the notation of the given language is recognized
+        assert_eq!(parse_str("2e Rue", Language::En), None);
+        assert_eq!(parse_str("3de Laan", Language::Es), None);
+        assert_eq!(parse_str("2da Calle", Language::It), None);
+        assert_eq!(parse_str("1. Straße", Language::Fr), None);
+        assert_eq!(parse_str("21st Av", Language::De), None);
+
+        // English ordinals must use the suffix of their last digit
+        assert_eq!(parse_str("2st Av", Language::En), None);
+        assert_eq!(
+            parse_str("12th Av", Language::En),
+            Some(String::from("12th"))
+        );
+    }
+}
@@ -0,0 +1,477 @@
+use super::*;
+use crate::{Context, Name, Source};
+use regex::Regex;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+///
+/// A generator of synonyms for a single network name
+///
+pub trait Synonym: Send + Sync {
+    ///
+    /// Unique identifier of the generator
+    ///
+    fn id(&self) -> String;
+
+    fn generate(&self, name: &Name, context: &Context) -> Vec<Name>;
+}
+
+///
+/// Synonym generator backed by one of the `syn_` functions
+///
+pub struct FnSynonym {
+    id: &'static str,
+    generator: fn(&Name, &Context) -> Vec<Name>,
+}
+
+impl FnSynonym {
+    pub fn new(id: &'static str, generator: fn(&Name, &Context) -> Vec<Name>) -> Self {
+        FnSynonym {
+            id: id,
+            generator: generator,
+        }
+    }
+}
+
+impl Synonym for FnSynonym {
+    fn id(&self) -> String {
+        String::from(self.id)
+    }
+
+    fn generate(&self, name: &Name, context: &Context) -> Vec<Name> {
+        (self.generator)(name, context)
+    }
+}
+
+///
+/// A single declarative synonym rule as read from a rules file
+///
+/// [{
+///     "country": "US",
+///     "region": "NY",
+///     "pattern": "(?i)^Beach (?P<rest>.*)$",
+///     "template": "B $rest",
+///     "priority": -1
+/// }]
+///
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct InputRule {
+    pub country: String,
+    pub region: Option<String>,
+
+    /// Regex matched against the display form of the name
+    pub pattern: String,
+
+    /// Replacement template, able to reference capture groups from the pattern
+    pub template: String,
+
+    /// Priority of the generated synonym, defaulting to -1
+    pub priority: Option<i8>,
+}
+
+///
+/// Synonym generator backed by a regex pattern & replacement template
+///
+pub struct RuleSynonym {
+    pattern: Regex,
+    template: String,
+    priority: i8,
+}
+
+impl RuleSynonym {
+    pub fn new(pattern: &str, template: impl ToString, priority: i8) -> Result<Self, String> {
+        let pattern = match Regex::new(pattern) {
+            Ok(pattern) => pattern,
+            Err(err) => {
+                return Err(format!("Invalid synonym pattern {}: {}", pattern, err));
+            }
+        };
+
+        Ok(RuleSynonym {
+            pattern: pattern,
+            template: template.to_string(),
+            priority: priority,
+        })
+    }
+}
+
+impl Synonym for RuleSynonym {
+    fn id(&self) -> String {
+        format!("{} => {} ({})", self.pattern, self.template, self.priority)
+    }
+
+    fn generate(&self, name: &Name, context: &Context) -> Vec<Name> {
+        if !self.pattern.is_match(&name.display) {
+            return Vec::new();
+        }
+
+        let display = self
+            .pattern
+            .replace(&name.display, self.template.as_str())
+            .to_string();
+
+        if display == name.display {
+            return Vec::new();
+        }
+
+        vec![Name::new(
+            display,
+            self.priority,
+            Some(Source::Generated),
+            &context,
+        )]
+    }
+}
+
+#[derive(Clone)]
+struct Entry {
+    country: String,
+    region: Option<String>,
+    generator: Arc<dyn Synonym>,
+}
+
+///
+/// Synonym generators keyed by country & optionally region, run in
+/// the order they were registered for each network name
+///
+#[derive(Clone)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    ///
+    /// Create a registry without any generators
+    ///
+    pub fn new() -> Self {
+        Registry {
+            entries: Vec::new(),
+        }
+    }
+
+    ///
+    /// Register a generator that runs for the given country, limited
+    /// to the given region if one is given
+    ///
+    pub fn register(&mut self, country: &str, region: Option<&str>, generator: Arc<dyn Synonym>) {
+        self.entries.push(Entry {
+            country: country.to_uppercase(),
+            region: region.map(|region| region.to_uppercase()),
+            generator: generator,
+        });
+    }
+
+    ///
+    /// Register the declarative rules from a JSON rules file
+    ///
+    pub fn load(&mut self, path: &String) -> Result<(), String> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => {
+                return Err(format!("Unable to open synonyms file: {}", err));
+            }
+        };
+
+        let rules: Vec<InputRule> = match serde_json::from_reader(BufReader::new(file)) {
+            Ok(rules) => rules,
+            Err(err) => {
+                return Err(format!("Invalid synonyms file: {}", err));
+            }
+        };
+
+        self.rules(rules)
+    }
+
+    ///
+    /// Register a list of declarative rules
+    ///
+    pub fn rules(&mut self, rules: Vec<InputRule>) -> Result<(), String> {
+        for rule in rules {
+            let synonym =
+                RuleSynonym::new(&rule.pattern, rule.template, rule.priority.unwrap_or(-1))?;
+
+            self.register(
+                &rule.country,
+                rule.region.as_ref().map(|region| region.as_str()),
+                Arc::new(synonym),
+            );
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Ids of all registered generators, in the order they run
+    ///
+    pub fn ids(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .map(|entry| match entry.region {
+                Some(ref region) => {
+                    format!("{}-{}: {}", entry.country, region, entry.generator.id())
+                }
+                None => format!("{}: {}", entry.country, entry.generator.id()),
+            })
+            .collect()
+    }
+
+    ///
+    /// Generate the synonyms of a name from all generators applying to the context
+    ///
+    pub fn generate(&self, name: &Name, context: &Context) -> Vec<Name> {
+        let mut synonyms = Vec::new();
+
+        for entry in self.entries.iter() {
+            if entry.country != context.country {
+                continue;
+            }
+
+            if entry.region.is_some() && entry.region != context.region {
+                continue;
+            }
+
+            synonyms.append(&mut entry.generator.generate(name, context));
+        }
+
+        synonyms
+    }
+}
+
+///
+/// Built in synonym generators as (country, region, id, generator)
+///
+static BUILTIN: &[(&str, Option<&str>, &str, fn(&Name, &Context) -> Vec<Name>)] = &[
+    ("US", None, "syn_number_suffix", syn_number_suffix),
+    ("US", None, "syn_written_numeric", syn_written_numeric),
+    ("US", None, "syn_state_hwy", syn_state_hwy),
+    ("US", None, "syn_us_hwy", syn_us_hwy),
+    ("US", None, "syn_us_cr", syn_us_cr),
+    ("US", None, "syn_us_famous", syn_us_famous),
+    ("US", Some("NY"), "syn_ny_beach", syn_ny_beach),
+    ("CA", None, "syn_ca_hwy", syn_ca_hwy),
+    ("CA", Some("QC"), "syn_ca_french", syn_ca_french),
+    ("DE", None, "syn_de_street", syn_de_street),
+    ("DE", None, "syn_de_eszett", syn_de_eszett),
+    ("DE", None, "syn_de_sankt", syn_de_sankt),
+    ("DE", None, "syn_written_ordinal", syn_written_ordinal),
+    ("AT", None, "syn_de_street", syn_de_street),
+    ("AT", None, "syn_de_eszett", syn_de_eszett),
+    ("AT", None, "syn_de_sankt", syn_de_sankt),
+    ("AT", None, "syn_written_ordinal", syn_written_ordinal),
+    ("CH", None, "syn_de_street", syn_de_street),
+    ("CH", None, "syn_de_eszett", syn_de_eszett),
+    ("CH", None, "syn_de_sankt", syn_de_sankt),
+    ("CH", None, "syn_written_ordinal", syn_written_ordinal),
+    ("FR", None, "syn_fr_particle", syn_fr_particle),
+    ("FR", None, "syn_fr_saint", syn_fr_saint),
+    ("FR", None, "syn_written_ordinal", syn_written_ordinal),
+    ("BE", None, "syn_fr_particle", syn_fr_particle),
+    ("BE", None, "syn_fr_saint", syn_fr_saint),
+    ("BE", None, "syn_written_ordinal", syn_written_ordinal),
+    ("LU", None, "syn_fr_particle", syn_fr_particle),
+    ("LU", None, "syn_fr_saint", syn_fr_saint),
+    ("LU", None, "syn_written_ordinal", syn_written_ordinal),
+    ("GB", None, "syn_gb_road", syn_gb_road),
+    ("IE", None, "syn_gb_road", syn_gb_road),
+    ("AU", None, "syn_au_hwy", syn_au_hwy),
+    ("NZ", None, "syn_nz_hwy", syn_nz_hwy),
+    ("ES", None, "syn_es_street", syn_es_street),
+    ("ES", None, "syn_es_honorific", syn_es_honorific),
+    ("ES", None, "syn_written_ordinal", syn_written_ordinal),
+    ("MX", None, "syn_es_street", syn_es_street),
+    ("MX", None, "syn_es_honorific", syn_es_honorific),
+    ("MX", None, "syn_written_ordinal", syn_written_ordinal),
+    ("AR", None, "syn_es_street", syn_es_street),
+    ("AR", None, "syn_es_honorific", syn_es_honorific),
+    ("AR", None, "syn_written_ordinal", syn_written_ordinal),
+    ("CO", None, "syn_es_street", syn_es_street),
+    ("CO", None, "syn_es_honorific", syn_es_honorific),
+    ("CO", None, "syn_written_ordinal", syn_written_ordinal),
+    ("PT", None, "syn_written_ordinal", syn_written_ordinal),
+    ("BR", None, "syn_written_ordinal", syn_written_ordinal),
+    ("IT", None, "syn_written_ordinal", syn_written_ordinal),
+    ("NL", None, "syn_written_ordinal", syn_written_ordinal),
+    ("RU", None, "syn_written_ordinal", syn_written_ordinal),
+];
+
+impl Default for Registry {
+    ///
+    /// Registry of the built in synonym generators
+    ///
+    fn default() -> Self {
+        let mut registry = Registry::new();
+
+        for (country, region, id, generator) in BUILTIN.iter() {
+            registry.register(country, *region, Arc::new(FnSynonym::new(*id, *generator)));
+        }
+
+        registry
+    }
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.ids()).finish()
+    }
+}
+
+impl PartialEq for Registry {
+    fn eq(&self, other: &Registry) -> bool {
+        self.ids() == other.ids()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tokens;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_registry_default() {
+        let registry = Registry::default();
+        let context = Context::new(
+            String::from("us"),
+            Some(String::from("ny")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            registry.generate(
+                &Name::new(String::from("Beach 31st St"), 0, None, &context),
+                &context
+            ),
+            vec![
+                Name::new(
+                    String::from("Beach 31st St"),
+                    1,
+                    Some(Source::Generated),
+                    &context
+                ),
+                Name::new(
+                    String::from("B 31st St"),
+                    -1,
+                    Some(Source::Generated),
+                    &context
+                ),
+            ]
+        );
+
+        // region specific generators do not run outside of their region
+        let context = Context::new(
+            String::from("us"),
+            Some(String::from("nj")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            registry.generate(
+                &Name::new(String::from("Beach 31st St"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_registry_rules() {
+        let mut registry = Registry::new();
+        registry
+            .rules(vec![
+                InputRule {
+                    country: String::from("de"),
+                    region: None,
+                    pattern: String::from(r"(?i)^(?P<name>.+)stra(ss|ß)e$"),
+                    template: String::from("${name}str"),
+                    priority: None,
+                },
+                InputRule {
+                    country: String::from("de"),
+                    region: Some(String::from("by")),
+                    pattern: String::from(r"(?i)^(?P<name>.+)weg$"),
+                    template: String::from("${name} Weg"),
+                    priority: Some(-2),
+                },
+            ])
+            .unwrap();
+
+        let context = Context::new(
+            String::from("de"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            registry.generate(
+                &Name::new(String::from("Hauptstraße"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("Hauptstr"),
+                -1,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert_eq!(
+            registry.generate(
+                &Name::new(String::from("Birkenweg"), 0, None, &context),
+                &context
+            ),
+            vec![]
+        );
+
+        let context = Context::new(
+            String::from("de"),
+            Some(String::from("by")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        assert_eq!(
+            registry.generate(
+                &Name::new(String::from("Birkenweg"), 0, None, &context),
+                &context
+            ),
+            vec![Name::new(
+                String::from("Birken Weg"),
+                -2,
+                Some(Source::Generated),
+                &context
+            )]
+        );
+
+        assert!(registry
+            .rules(vec![InputRule {
+                country: String::from("de"),
+                region: None,
+                pattern: String::from(r"(unclosed"),
+                template: String::from(""),
+                priority: None,
+            }])
+            .is_err());
+    }
+
+    #[test]
+    fn test_registry_load() {
+        let path = std::env::temp_dir().join("pt2itp-synonyms.json");
+        std::fs::write(
+            &path,
+            r#"[{ "country": "us", "pattern": "^Mount (?P<rest>.*)$", "template": "Mt $rest" }]"#,
+        )
+        .unwrap();
+
+        let mut registry = Registry::new();
+        registry.load(&path.to_str().unwrap().to_string()).unwrap();
+
+        assert_eq!(
+            registry.ids(),
+            vec![String::from("US: ^Mount (?P<rest>.*)$ => Mt $rest (-1)")]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(registry.load(&path.to_str().unwrap().to_string()).is_err());
+    }
+}
@@ -3,7 +3,7 @@ use regex::Regex;
 use unicode_segmentation::UnicodeSegmentation;
 
 ///
-/// Titlecase input strings
+/// Titlecase input strings, following the casing rules of the context's country
 ///
 
 pub fn titlecase(text: &String, context: &Context) -> String {
@@ -13,7 +13,9 @@ pub fn titlecase(text: &String, context: &Context) -> String {
                 .unwrap();
     }
 
-    let mut text = text.trim().to_lowercase();
+    let casing = Casing::new(&context.country);
+
+    let mut text = casing.lowercase(text.trim());
     text = Regex::new(r"\s+")
         .unwrap()
         .replace_all(&text, " ")
@@ -25,7 +27,7 @@ pub fn titlecase(text: &String, context: &Context) -> String {
         let word = &text[last_match..mat.start()];
         if word.len() > 0 {
             word_count = word_count + 1;
-            new.push_str(&capitalize(word, word_count, context));
+            new.push_str(&casing.capitalize(word, word_count));
         }
         new.push_str(&mat.as_str());
         last_match = mat.end();
@@ -34,7 +36,7 @@ pub fn titlecase(text: &String, context: &Context) -> String {
     if last_match < text.len() {
         let word = &text[last_match..];
         word_count = word_count + 1;
-        new.push_str(&capitalize(word, word_count, context));
+        new.push_str(&casing.capitalize(word, word_count));
     }
 
     if context.country == String::from("US") || context.country == String::from("CA") {
@@ -44,40 +46,204 @@ pub fn titlecase(text: &String, context: &Context) -> String {
     new
 }
 
-pub fn capitalize(word: &str, word_count: usize, context: &Context) -> String {
-    const MINOR_EN: [&str; 40] = [
-        "a", "an", "and", "as", "at", "but", "by", "en", "for", "from", "how", "if", "in",
-        "neither", "nor", "of", "on", "only", "onto", "out", "or", "per", "so", "than", "that",
-        "the", "to", "until", "up", "upon", "v", "v.", "versus", "vs", "vs.", "via", "when",
-        "with", "without", "yet",
-    ];
+const MINOR_EN: [&str; 40] = [
+    "a", "an", "and", "as", "at", "but", "by", "en", "for", "from", "how", "if", "in", "neither",
+    "nor", "of", "on", "only", "onto", "out", "or", "per", "so", "than", "that", "the", "to",
+    "until", "up", "upon", "v", "v.", "versus", "vs", "vs.", "via", "when", "with", "without",
+    "yet",
+];
+
+const MAJOR_EN: [&str; 2] = ["us", "dc"];
+
+const MINOR_DE: [&str; 19] = [
+    "am", "an", "auf", "bei", "dem", "den", "der", "des", "die", "du", "im", "in", "und", "unter",
+    "vom", "von", "zu", "zum", "zur",
+];
+
+const MINOR_FR: [&str; 15] = [
+    "à", "au", "aux", "d", "de", "des", "du", "en", "et", "l", "la", "le", "les", "sous", "sur",
+];
+
+const MINOR_ES: [&str; 8] = ["de", "del", "e", "el", "la", "las", "los", "y"];
+
+const MINOR_IT: [&str; 13] = [
+    "d", "da", "dal", "dalla", "degli", "dei", "del", "della", "delle", "di", "e", "l", "la",
+];
+
+const MINOR_PT: [&str; 6] = ["da", "das", "de", "do", "dos", "e"];
+
+const MINOR_NL: [&str; 12] = [
+    "'t", "aan", "bij", "de", "den", "der", "het", "in", "op", "ten", "ter", "van",
+];
+
+const ELISION_FR: [&str; 2] = ["d", "l"];
+
+const ELISION_IT: [&str; 7] = ["all", "d", "dall", "dell", "l", "nell", "sull"];
+
+///
+/// Casing rules of the language(s) spoken in a given country
+///
+#[derive(Default)]
+struct Casing {
+    /// Words that are always uppercased
+    major: Vec<&'static str>,
+
+    /// Words that are lowercased unless they are the first word
+    minor: Vec<&'static str>,
+
+    /// Articles & prepositions elided onto the following word, ie: l'Église, dell'Orso
+    elision: Vec<&'static str>,
 
-    const MAJOR_EN: [&str; 2] = ["us", "dc"];
+    /// Irish & Scottish Mc, Mac & O' prefixes, ie: McDonald, MacLeod, O'Brien
+    gaelic: bool,
 
-    const MINOR_DE: [&str; 1] = ["du"];
+    /// Dutch IJ digraph, ie: IJsselmeer
+    ij: bool,
 
-    if (context.country == String::from("US") || context.country == String::from("CA"))
-        && MAJOR_EN.contains(&word)
-    {
-        return String::from(word).to_uppercase();
+    /// Turkic dotted & dotless i, ie: İstiklal, Işık
+    turkic: bool,
+}
+
+impl Casing {
+    fn new(country: &str) -> Self {
+        match country {
+            "US" | "CA" => Casing {
+                major: MAJOR_EN.to_vec(),
+                minor: MINOR_EN.to_vec(),
+                ..Default::default()
+            },
+            "GB" | "IE" => Casing {
+                minor: MINOR_EN.to_vec(),
+                gaelic: true,
+                ..Default::default()
+            },
+            "AU" | "NZ" => Casing {
+                minor: MINOR_EN.to_vec(),
+                ..Default::default()
+            },
+            "DE" | "AT" => Casing {
+                minor: MINOR_DE.to_vec(),
+                ..Default::default()
+            },
+            "CH" => Casing {
+                minor: [&MINOR_DE[..], &MINOR_FR[..], &MINOR_IT[..]].concat(),
+                elision: [&ELISION_FR[..], &ELISION_IT[..]].concat(),
+                ..Default::default()
+            },
+            "FR" | "LU" => Casing {
+                minor: MINOR_FR.to_vec(),
+                elision: ELISION_FR.to_vec(),
+                ..Default::default()
+            },
+            "BE" => Casing {
+                minor: [&MINOR_FR[..], &MINOR_NL[..]].concat(),
+                elision: ELISION_FR.to_vec(),
+                ij: true,
+                ..Default::default()
+            },
+            "NL" => Casing {
+                minor: MINOR_NL.to_vec(),
+                ij: true,
+                ..Default::default()
+            },
+            "ES" | "MX" | "AR" | "CO" | "CL" | "PE" => Casing {
+                minor: MINOR_ES.to_vec(),
+                ..Default::default()
+            },
+            "IT" => Casing {
+                minor: MINOR_IT.to_vec(),
+                elision: ELISION_IT.to_vec(),
+                ..Default::default()
+            },
+            "PT" | "BR" => Casing {
+                minor: MINOR_PT.to_vec(),
+                ..Default::default()
+            },
+            "TR" | "AZ" => Casing {
+                turkic: true,
+                ..Default::default()
+            },
+            _ => Casing::default(),
+        }
     }
-    // don't apply lower casing to the first word in the string
-    if word_count > 1 {
-        if (context.country == String::from("US") || context.country == String::from("CA"))
-            && MINOR_EN.contains(&word)
-        {
-            return String::from(word);
-        } else if context.country == String::from("DE") && MINOR_DE.contains(&word) {
+
+    ///
+    /// Lowercase a string, mapping I to ı & İ to i in Turkic languages
+    /// where the default mapping would lose or add a dot
+    ///
+    fn lowercase(&self, text: &str) -> String {
+        if self.turkic {
+            text.replace('I', "ı").replace('İ', "i").to_lowercase()
+        } else {
+            text.to_lowercase()
+        }
+    }
+
+    fn capitalize(&self, word: &str, word_count: usize) -> String {
+        lazy_static! {
+            static ref GAELIC: Regex =
+                Regex::new(r"^(?P<prefix>mc|mac|o')(?P<rest>\w.*)$").unwrap();
+            static ref ELISION: Regex = Regex::new(r"^(?P<prefix>\w+)'(?P<rest>\w.*)$").unwrap();
+        }
+
+        if self.major.contains(&word) {
+            return String::from(word).to_uppercase();
+        }
+        // don't apply lower casing to the first word in the string
+        if word_count > 1 && self.minor.contains(&word) {
             return String::from(word);
         }
+
+        if let Some(capture) = ELISION.captures(word) {
+            if self.elision.contains(&&capture["prefix"]) {
+                let prefix = if word_count > 1 {
+                    capture["prefix"].to_string()
+                } else {
+                    self.upper_first(&capture["prefix"])
+                };
+
+                return format!("{}'{}", prefix, self.upper_first(&capture["rest"]));
+            }
+        }
+
+        if self.gaelic {
+            if let Some(capture) = GAELIC.captures(word) {
+                let rest = &capture["rest"];
+
+                // Mac is also the start of many unrelated words, ie: Macclesfield, Mackay, Macon
+                if &capture["prefix"] != "mac"
+                    || (rest.chars().count() >= 3
+                        && !rest.starts_with(|c: char| "aeiouyhkc".contains(c)))
+                {
+                    return format!(
+                        "{}{}",
+                        self.upper_first(&capture["prefix"]),
+                        self.upper_first(rest)
+                    );
+                }
+            }
+        }
+
+        if self.ij && word.starts_with("ij") {
+            return format!("IJ{}", &word[2..]);
+        }
+
+        self.upper_first(word)
     }
 
-    let mut graphemes = UnicodeSegmentation::graphemes(word, true);
-    let first_grapheme = match graphemes.next() {
-        Some(g) => g,
-        None => return String::from(word),
-    };
-    first_grapheme.to_uppercase() + graphemes.as_str()
+    fn upper_first(&self, word: &str) -> String {
+        let mut graphemes = UnicodeSegmentation::graphemes(word, true);
+        let first_grapheme = match graphemes.next() {
+            Some(g) => g,
+            None => return String::from(word),
+        };
+
+        if self.turkic && first_grapheme.starts_with('i') {
+            return format!("İ{}{}", &first_grapheme[1..], graphemes.as_str());
+        }
+
+        first_grapheme.to_uppercase() + graphemes.as_str()
+    }
 }
 
 pub fn normalize_cardinals(text: &str) -> String {
@@ -110,7 +276,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
@@ -243,7 +409,7 @@ mod tests {
         let context = Context::new(
             String::from("de"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
         assert_eq!(
             titlecase(&String::from(" hast Du recht"), &context),
@@ -253,5 +419,129 @@ mod tests {
             titlecase(&String::from("a 9, 80939 münchen, germany"), &context),
             String::from("A 9, 80939 München, Germany")
         );
+
+        let context = Context::new(
+            String::from("de"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("straße des 17. juni"), &context),
+            String::from("Straße des 17. Juni")
+        );
+        assert_eq!(
+            titlecase(&String::from("AN DER ALSTER"), &context),
+            String::from("An der Alster")
+        );
+
+        let context = Context::new(
+            String::from("fr"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("rue de la paix"), &context),
+            String::from("Rue de la Paix")
+        );
+        assert_eq!(
+            titlecase(&String::from("place de l'église"), &context),
+            String::from("Place de l'Église")
+        );
+        assert_eq!(
+            titlecase(&String::from("l'isle-adam"), &context),
+            String::from("L'Isle-Adam")
+        );
+
+        let context = Context::new(
+            String::from("es"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("calle de los reyes"), &context),
+            String::from("Calle de los Reyes")
+        );
+
+        let context = Context::new(
+            String::from("it"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("via dell'orso"), &context),
+            String::from("Via dell'Orso")
+        );
+        assert_eq!(
+            titlecase(&String::from("piazza della repubblica"), &context),
+            String::from("Piazza della Repubblica")
+        );
+
+        let context = Context::new(
+            String::from("pt"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("rua dos anjos"), &context),
+            String::from("Rua dos Anjos")
+        );
+
+        let context = Context::new(
+            String::from("nl"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("ijsselstraat"), &context),
+            String::from("IJsselstraat")
+        );
+        assert_eq!(
+            titlecase(&String::from("laan van nieuw oost-indië"), &context),
+            String::from("Laan van Nieuw Oost-Indië")
+        );
+
+        let context = Context::new(
+            String::from("ie"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("o'connell street"), &context),
+            String::from("O'Connell Street")
+        );
+        assert_eq!(
+            titlecase(&String::from("mcdonald road"), &context),
+            String::from("McDonald Road")
+        );
+        assert_eq!(
+            titlecase(&String::from("macleod way"), &context),
+            String::from("MacLeod Way")
+        );
+        assert_eq!(
+            titlecase(&String::from("mackay road"), &context),
+            String::from("Mackay Road")
+        );
+        assert_eq!(
+            titlecase(&String::from("machynlleth road"), &context),
+            String::from("Machynlleth Road")
+        );
+
+        let context = Context::new(
+            String::from("tr"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+        assert_eq!(
+            titlecase(&String::from("İSTİKLAL CADDESİ"), &context),
+            String::from("İstiklal Caddesi")
+        );
+        assert_eq!(
+            titlecase(&String::from("IŞIK SOKAK"), &context),
+            String::from("Işık Sokak")
+        );
+        assert_eq!(
+            titlecase(&String::from("ilk sokak"), &context),
+            String::from("İlk Sokak")
+        );
     }
 }
@@ -1,14 +1,25 @@
 use super::diacritics;
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
 use geocoder_abbreviations::{Token, TokenType};
 use neon::prelude::*;
-use regex::Regex;
+use regex::{Regex, RegexSet};
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::BufReader;
+use unicode_segmentation::UnicodeSegmentation;
+
+// Countries using english tokens, skipped to get around lookahead token replacement errors
+const SKIP_REGEX: [&str; 10] = ["US", "GB", "CA", "IE", "IS", "SG", "FI", "AU", "NZ", "GG"];
+
+// Countries whose names are commonly written in scripts without spaces between words
+const SEGMENTED: [&str; 6] = ["JP", "CN", "TW", "HK", "MO", "TH"];
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Tokens {
     tokens: HashMap<String, ParsedToken>,
-    regex_tokens: HashMap<String, ParsedToken>,
-    multi_tokens: HashMap<String, ParsedToken>,
+    regex_tokens: RegexReplacer,
+    multi_tokens: MultiReplacer,
 }
 
 impl Tokens {
@@ -16,106 +27,86 @@ impl Tokens {
         tokens: HashMap<String, ParsedToken>,
         regex_tokens: HashMap<String, ParsedToken>,
         multi_tokens: HashMap<String, ParsedToken>,
-    ) -> Self {
-        Tokens {
+    ) -> Result<Self, String> {
+        Ok(Tokens {
             tokens: tokens,
-            regex_tokens: regex_tokens,
-            multi_tokens: multi_tokens,
-        }
+            regex_tokens: RegexReplacer::new(&regex_tokens)?,
+            multi_tokens: MultiReplacer::new(&multi_tokens),
+        })
     }
 
-    pub fn generate(languages: Vec<String>) -> Self {
-        let import: HashMap<String, Vec<Token>> =
-            geocoder_abbreviations::config(languages).unwrap();
-        let mut map: HashMap<String, ParsedToken> = HashMap::new();
-        let mut regex_map: HashMap<String, ParsedToken> = HashMap::new();
-        let mut multi_map: HashMap<String, ParsedToken> = HashMap::new();
-        // regex_map contains all tokens with `regex: true`, regardless of spanBoundaries setting
-        // multi_map contains all tokens with a `spanBoundaries` property
-        // map contains the remaining tokens that does not spanBoundaries and is not a regex
+    pub fn generate(languages: Vec<String>) -> Result<Self, String> {
+        let maps = TokenMaps::import(languages)?;
 
-        // the priority for creating the token maps and applying transformations on the names for linking is:
-        // 1) regex, 2) span boundaries, 3) all others
-        for language in import.keys() {
-            for group in import.get(language).unwrap() {
-                if group.regex {
-                    for tk in &group.tokens {
-                        regex_map.insert(
-                            tk.to_lowercase(),
-                            ParsedToken::new(
-                                group.canonical.to_lowercase(),
-                                group.token_type.to_owned(),
-                            ),
-                        );
-                    }
-                } else if group.span_boundaries != None {
-                    for tk in &group.tokens {
-                        let token = &tk.to_lowercase();
-                        let canonical = &group.canonical.to_lowercase();
-                        if token != canonical {
-                            multi_map.insert(
-                                diacritics(&tk.to_lowercase()),
-                                ParsedToken::new(
-                                    diacritics(&group.canonical.to_lowercase()),
-                                    group.token_type.to_owned(),
-                                ),
-                            );
-                        }
-                    }
-                } else {
-                    for tk in &group.tokens {
-                        map.insert(
-                            diacritics(&tk.to_lowercase()),
-                            ParsedToken::new(
-                                diacritics(&group.canonical.to_lowercase()),
-                                group.token_type.to_owned(),
-                            ),
-                        );
-                    }
-                }
+        Tokens::new(maps.tokens, maps.regex_tokens, maps.multi_tokens)
+    }
+
+    ///
+    /// Generate the tokens of the given languages, merging the custom token
+    /// groups of a JSON token file over them
+    ///
+    pub fn load(languages: Option<Vec<String>>, path: &String) -> Result<Self, String> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => {
+                return Err(format!("Unable to open tokens file: {}", err));
             }
-        }
+        };
 
-        Tokens {
-            tokens: map,
-            regex_tokens: regex_map,
-            multi_tokens: multi_map,
+        let groups: Vec<InputToken> = match serde_json::from_reader(BufReader::new(file)) {
+            Ok(groups) => groups,
+            Err(err) => {
+                return Err(format!("Invalid tokens file: {}", err));
+            }
+        };
+
+        let mut maps = match languages {
+            None => TokenMaps::default(),
+            Some(languages) => TokenMaps::import(languages)?,
+        };
+
+        for group in groups {
+            maps.merge(group)?;
         }
+
+        Tokens::new(maps.tokens, maps.regex_tokens, maps.multi_tokens)
     }
 
     pub fn process(&self, text: &String, country: &String) -> Vec<Tokenized> {
-        let mut tokens = self.tokenize(&text);
+        let tokens = if !country.is_empty()
+            && !SKIP_REGEX.contains(&country.as_str())
+            && !(self.regex_tokens.is_empty() && self.multi_tokens.is_empty())
+        {
+            let normalized_full_text = diacritics(&text.to_lowercase());
+            let normalized_full_text = self.regex_tokens.replace_all(normalized_full_text);
+            let normalized_full_text = self.multi_tokens.replace_all(&normalized_full_text);
 
-        let mut normalized_full_text = diacritics(&text.to_lowercase());
-        let skip_regex_list = vec![
-            String::from("US"),
-            String::from("GB"),
-            String::from("CA"),
-            String::from("IE"),
-            String::from("IS"),
-            String::from("SG"),
-            String::from("FI"),
-            String::from("AU"),
-            String::from("NZ"),
-            String::from("GG"),
-        ]; // add countries that are using english tokens here to get around lookahead token replacement errors
+            self.tokenize(&normalized_full_text)
+        } else {
+            self.tokenize(&text)
+        };
+
+        self.lookup(tokens, country)
+    }
+
+    ///
+    /// Replace each token by its canonical form & type, segmenting the tokens
+    /// of countries written without spaces between words
+    ///
+    fn lookup(&self, tokens: Vec<String>, country: &String) -> Vec<Tokenized> {
+        let tokens: Vec<String> = if SEGMENTED.contains(&country.as_str()) {
+            tokens
+                .into_iter()
+                .flat_map(|token| match self.tokens.contains_key(&token) {
+                    true => vec![token],
+                    false => segment(&token),
+                })
+                .collect()
+        } else {
+            tokens
+        };
 
         let mut tokenized: Vec<Tokenized> = Vec::with_capacity(tokens.len());
-        if !country.is_empty() && !skip_regex_list.contains(&country) {
-            for (regex_string, v) in self.regex_tokens.iter() {
-                let re = Regex::new(&format!(r"{}", regex_string)).unwrap();
-                let canonical: &str = &*v.canonical; // convert from std::string::String -> &str
-                normalized_full_text = re.replace_all(&normalized_full_text, canonical).to_string();
-                tokens = self.tokenize(&normalized_full_text);
-            }
-            for (multi_string, v) in self.multi_tokens.iter() {
-                let canonical: &str = &*v.canonical; // convert from std::string::String -> &str
-                normalized_full_text = normalized_full_text
-                    .replace(multi_string, canonical)
-                    .to_string();
-                tokens = self.tokenize(&normalized_full_text);
-            }
-        }
         for token in &tokens {
             match self.tokens.get(token) {
                 None => {
@@ -191,6 +182,336 @@ impl Tokens {
     }
 }
 
+impl Default for Tokens {
+    fn default() -> Self {
+        Tokens {
+            tokens: HashMap::new(),
+            regex_tokens: RegexReplacer::default(),
+            multi_tokens: MultiReplacer::default(),
+        }
+    }
+}
+
+///
+/// A custom token group, using the same JSON schema as geocoder-abbreviations
+///
+/// [{
+///     "tokens": ["Carrera", "Cra", "Kr"],
+///     "full": "Carrera",
+///     "canonical": "Cra",
+///     "type": "way"
+/// }]
+///
+/// A group replaces any existing group of its tokens, so a token can be re-typed
+/// or have its type removed with `"type": null`. Groups with `"remove": true`
+/// drop their tokens altogether
+///
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InputToken {
+    pub tokens: Vec<String>,
+    pub full: Option<String>,
+    pub canonical: Option<String>,
+
+    #[serde(rename = "type")]
+    pub token_type: Option<String>,
+
+    #[serde(default)]
+    pub regex: bool,
+
+    pub span_boundaries: Option<u8>,
+
+    #[serde(default)]
+    pub remove: bool,
+}
+
+///
+/// Token maps as they are built up before being compiled into a Tokens instance
+///
+/// regex_tokens contains all tokens with `regex: true`, regardless of spanBoundaries setting
+/// multi_tokens contains all tokens with a `spanBoundaries` property
+/// tokens contains the remaining tokens that does not spanBoundaries and is not a regex
+///
+/// the priority for creating the token maps and applying transformations on the names for linking is:
+/// 1) regex, 2) span boundaries, 3) all others
+///
+#[derive(Default)]
+struct TokenMaps {
+    tokens: HashMap<String, ParsedToken>,
+    regex_tokens: HashMap<String, ParsedToken>,
+    multi_tokens: HashMap<String, ParsedToken>,
+}
+
+impl TokenMaps {
+    ///
+    /// Import the token groups of the given languages from geocoder-abbreviations
+    ///
+    fn import(languages: Vec<String>) -> Result<Self, String> {
+        let mut maps = TokenMaps::default();
+
+        let import: HashMap<String, Vec<Token>> = match geocoder_abbreviations::config(languages) {
+            Ok(import) => import,
+            Err(err) => {
+                return Err(format!("Unable to import tokens: {:?}", err));
+            }
+        };
+
+        for language in import.keys() {
+            for group in import.get(language).unwrap() {
+                maps.insert(
+                    &group.tokens,
+                    &group.canonical,
+                    &group.token_type,
+                    group.regex,
+                    group.span_boundaries != None,
+                );
+            }
+        }
+
+        Ok(maps)
+    }
+
+    fn insert(
+        &mut self,
+        tokens: &Vec<String>,
+        canonical: &String,
+        token_type: &Option<TokenType>,
+        regex: bool,
+        span_boundaries: bool,
+    ) {
+        if regex {
+            for tk in tokens {
+                self.regex_tokens.insert(
+                    tk.to_lowercase(),
+                    ParsedToken::new(canonical.to_lowercase(), token_type.to_owned()),
+                );
+            }
+        } else if span_boundaries {
+            for tk in tokens {
+                let token = &tk.to_lowercase();
+                let canonical = &canonical.to_lowercase();
+                if token != canonical {
+                    self.multi_tokens.insert(
+                        diacritics(&tk.to_lowercase()),
+                        ParsedToken::new(diacritics(canonical), token_type.to_owned()),
+                    );
+                }
+            }
+        } else {
+            for tk in tokens {
+                self.tokens.insert(
+                    diacritics(&tk.to_lowercase()),
+                    ParsedToken::new(diacritics(&canonical.to_lowercase()), token_type.to_owned()),
+                );
+            }
+        }
+    }
+
+    fn remove(&mut self, tokens: &Vec<String>) {
+        for tk in tokens {
+            self.regex_tokens.remove(&tk.to_lowercase());
+            self.multi_tokens.remove(&diacritics(&tk.to_lowercase()));
+            self.tokens.remove(&diacritics(&tk.to_lowercase()));
+        }
+    }
+
+    ///
+    /// Merge a custom token group over the existing groups
+    ///
+    fn merge(&mut self, group: InputToken) -> Result<(), String> {
+        self.remove(&group.tokens);
+
+        if group.remove {
+            return Ok(());
+        }
+
+        let canonical = match group.canonical {
+            Some(canonical) => canonical,
+            None => {
+                return Err(format!(
+                    "Token group {:?} requires a canonical token",
+                    group.tokens
+                ));
+            }
+        };
+
+        let token_type: Option<TokenType> = match group.token_type {
+            None => None,
+            Some(token_type) => {
+                // geocoder-abbreviations types are lowercase, ie: way, cardinal, postalBox
+                let mut chars = token_type.chars();
+                let variant = match chars.next() {
+                    Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                    None => String::new(),
+                };
+
+                match serde_json::from_value(serde_json::Value::String(variant)) {
+                    Ok(token_type) => Some(token_type),
+                    Err(_) => {
+                        return Err(format!("Invalid token type: {}", token_type));
+                    }
+                }
+            }
+        };
+
+        if group.regex {
+            // Custom patterns are compiled as they are inserted, lowercased
+            for tk in &group.tokens {
+                if let Err(err) = Regex::new(&tk.to_lowercase()) {
+                    return Err(format!("Invalid regex token {}: {}", tk, err));
+                }
+            }
+        }
+
+        self.insert(
+            &group.tokens,
+            &canonical,
+            &token_type,
+            group.regex,
+            group.span_boundaries != None,
+        );
+
+        Ok(())
+    }
+}
+
+///
+/// Regex token replacements, compiled once & applied in turn in the order of the token map.
+/// A RegexSet finds the patterns matching the text in a single pass so only those
+/// replacements are run, the text only being rescanned once a replacement changed it
+///
+#[derive(Debug, Clone)]
+struct RegexReplacer {
+    set: RegexSet,
+    patterns: Vec<Regex>,
+    canonicals: Vec<String>,
+}
+
+impl PartialEq for RegexReplacer {
+    fn eq(&self, other: &RegexReplacer) -> bool {
+        self.set.patterns() == other.set.patterns() && self.canonicals == other.canonicals
+    }
+}
+
+impl Default for RegexReplacer {
+    fn default() -> Self {
+        RegexReplacer {
+            set: RegexSet::empty(),
+            patterns: Vec::new(),
+            canonicals: Vec::new(),
+        }
+    }
+}
+
+impl RegexReplacer {
+    fn new(tokens: &HashMap<String, ParsedToken>) -> Result<Self, String> {
+        let mut patterns = Vec::with_capacity(tokens.len());
+        let mut canonicals = Vec::with_capacity(tokens.len());
+
+        for (token, parsed) in tokens.iter() {
+            match Regex::new(token) {
+                Ok(pattern) => patterns.push(pattern),
+                Err(err) => {
+                    return Err(format!("Invalid regex token {}: {}", token, err));
+                }
+            };
+
+            canonicals.push(parsed.canonical.clone());
+        }
+
+        let set = match RegexSet::new(patterns.iter().map(|pattern| pattern.as_str())) {
+            Ok(set) => set,
+            Err(err) => {
+                return Err(format!("Invalid regex tokens: {}", err));
+            }
+        };
+
+        Ok(RegexReplacer {
+            set: set,
+            patterns: patterns,
+            canonicals: canonicals,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    ///
+    /// Apply each replacement in order, equivalent to running every replacement
+    /// in turn, but only rescanning the text once a replacement has changed it
+    ///
+    fn replace_all(&self, mut text: String) -> String {
+        let mut next = 0;
+
+        while next < self.patterns.len() {
+            let pos = match self.set.matches(&text).into_iter().find(|pos| *pos >= next) {
+                Some(pos) => pos,
+                None => break,
+            };
+
+            text = self.patterns[pos]
+                .replace_all(&text, self.canonicals[pos].as_str())
+                .to_string();
+
+            next = pos + 1;
+        }
+
+        text
+    }
+}
+
+///
+/// Multi token (spanBoundaries) replacements, applied in a single pass by an Aho-Corasick
+/// automaton. Where several tokens match at the same position the longest one is replaced
+///
+#[derive(Debug, Clone)]
+struct MultiReplacer {
+    automaton: AhoCorasick,
+    tokens: Vec<String>,
+    canonicals: Vec<String>,
+}
+
+impl PartialEq for MultiReplacer {
+    fn eq(&self, other: &MultiReplacer) -> bool {
+        self.tokens == other.tokens && self.canonicals == other.canonicals
+    }
+}
+
+impl Default for MultiReplacer {
+    fn default() -> Self {
+        MultiReplacer::new(&HashMap::new())
+    }
+}
+
+impl MultiReplacer {
+    fn new(tokens: &HashMap<String, ParsedToken>) -> Self {
+        let mut keys: Vec<&String> = tokens.keys().collect();
+        keys.sort();
+
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(&keys);
+
+        MultiReplacer {
+            automaton: automaton,
+            tokens: keys.iter().map(|key| key.to_string()).collect(),
+            canonicals: keys
+                .iter()
+                .map(|key| tokens[*key].canonical.clone())
+                .collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    fn replace_all(&self, text: &String) -> String {
+        self.automaton.replace_all(text, &self.canonicals)
+    }
+}
+
 /// Simplified struct from geocoder_abbreviations::Token
 /// @TODO replace with geocoder_abbreviations::Token when additional traits are derived
 #[derive(Debug, PartialEq, Clone)]
@@ -220,6 +541,48 @@ impl Tokenized {
     }
 }
 
+///
+/// Split the CJK & Thai runs of a token into overlapping character bigrams, as they are
+/// written without spaces between words. The remaining parts of the token, ie: numbers
+/// or latin text, are kept as their own tokens
+///
+/// 中山北路 => 中山 山北 北路, 1丁目 => 1 丁目
+///
+pub fn segment(token: &String) -> Vec<String> {
+    lazy_static! {
+        static ref UNSPACED: Regex =
+            Regex::new(r"[\p{Han}\p{Hiragana}\p{Katakana}\p{Thai}\u30FC\uFF70]+").unwrap();
+    }
+
+    let mut segments = Vec::new();
+    let mut last = 0;
+
+    for mat in UNSPACED.find_iter(token) {
+        if mat.start() > last {
+            segments.push(token[last..mat.start()].to_string());
+        }
+
+        // Thai vowels & tone marks combine with the preceding consonant
+        let graphemes: Vec<&str> = UnicodeSegmentation::graphemes(mat.as_str(), true).collect();
+
+        if graphemes.len() <= 2 {
+            segments.push(mat.as_str().to_string());
+        } else {
+            for pair in graphemes.windows(2) {
+                segments.push(pair.concat());
+            }
+        }
+
+        last = mat.end();
+    }
+
+    if last < token.len() {
+        segments.push(token[last..].to_string());
+    }
+
+    segments
+}
+
 ///
 /// Change 'st' token_type to TokenType::Way ('Street')  or None ('Saint')
 ///
@@ -259,13 +622,86 @@ pub fn tokenize_name(mut cx: FunctionContext) -> JsResult<JsValue> {
     let context = cx.argument::<JsValue>(1)?;
     let context: crate::types::InputContext = neon_serde::from_value(&mut cx, context)
         .or_else(|e| cx.throw_error(format!("text/tokens - unable to assign context: {:?}", e)))?;
-    let context = crate::Context::from(context);
+    let context = crate::Context::try_from(context)
+        .or_else(|e| cx.throw_error(format!("text/tokens - invalid context: {}", e)))?;
     let tokenized = context.tokens.process(&name, &context.country);
 
     Ok(neon_serde::to_value(&mut cx, &tokenized)
         .or_else(|e| cx.throw_error(format!("text/tokens: {:?}", e)))?)
 }
 
+///
+/// Preview how a batch of names will be interpreted, returning for each input name
+/// the names it results in, including generated synonyms, each with its tokens
+///
+/// Names can either be a string or an object with a display, priority & language. They are
+/// treated as Network names, as these are the only names synonyms are generated for
+///
+pub fn tokenize_names(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let names = cx.argument::<JsValue>(0)?;
+    let names: Vec<serde_json::Value> = neon_serde::from_value(&mut cx, names)
+        .or_else(|e| cx.throw_error(format!("text/tokens - unable to assign names: {:?}", e)))?;
+    let context = cx.argument::<JsValue>(1)?;
+    let context: crate::types::InputContext = neon_serde::from_value(&mut cx, context)
+        .or_else(|e| cx.throw_error(format!("text/tokens - unable to assign context: {:?}", e)))?;
+    let context = crate::Context::try_from(context)
+        .or_else(|e| cx.throw_error(format!("text/tokens - invalid context: {}", e)))?;
+
+    let mut results: Vec<Vec<AnalyzedName>> = Vec::with_capacity(names.len());
+
+    for name in names {
+        let name: crate::types::name::InputName = match name {
+            serde_json::Value::String(display) => crate::types::name::InputName {
+                display: display,
+                priority: 0,
+                language: None,
+            },
+            name => match serde_json::from_value(name) {
+                Ok(name) => name,
+                Err(err) => {
+                    return cx.throw_error(format!("text/tokens - invalid name: {}", err));
+                }
+            },
+        };
+
+        let names = crate::Names::new(
+            vec![crate::Name::new(
+                name.display,
+                name.priority,
+                Some(crate::Source::Network),
+                &context,
+            )
+            .set_language(name.language)],
+            &context,
+        );
+
+        results.push(
+            names
+                .names
+                .into_iter()
+                .map(|name| AnalyzedName {
+                    tokenless: name.tokenless_string(),
+                    name: name,
+                })
+                .collect(),
+        );
+    }
+
+    Ok(neon_serde::to_value(&mut cx, &results)
+        .or_else(|e| cx.throw_error(format!("text/tokens: {:?}", e)))?)
+}
+
+///
+/// A Name along with its tokenless form, as returned by tokenize_names
+///
+#[derive(Serialize)]
+struct AnalyzedName {
+    #[serde(flatten)]
+    name: crate::Name,
+
+    tokenless: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -281,7 +717,7 @@ mod tests {
 
     #[test]
     fn test_remove_diacritics() {
-        let tokens = Tokens::new(HashMap::new(), HashMap::new(), HashMap::new());
+        let tokens = Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap();
 
         // diacritics are removed from latin text
         assert_eq!(
@@ -332,7 +768,7 @@ mod tests {
 
     #[test]
     fn test_tokenize() {
-        let tokens = Tokens::new(HashMap::new(), HashMap::new(), HashMap::new());
+        let tokens = Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap();
         assert_eq!(
             tokenized_string(tokens.process(&String::from(""), &String::from(""))),
             String::from("")
@@ -466,7 +902,7 @@ mod tests {
             ParsedToken::new(String::from("st"), Some(TokenType::Way)),
         );
 
-        let tokens = Tokens::new(map, regex_map, multi_map);
+        let tokens = Tokens::new(map, regex_map, multi_map).unwrap();
 
         assert_eq!(
             tokens.process(&String::from("Main Street"), &String::from("")),
@@ -498,9 +934,116 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_segment() {
+        let tokens = Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap();
+
+        assert_eq!(
+            tokenized_string(tokens.process(&String::from("中山北路"), &String::from("TW"))),
+            String::from("中山 山北 北路")
+        );
+        assert_eq!(
+            tokenized_string(tokens.process(&String::from("中山北路"), &String::from("US"))),
+            String::from("中山北路")
+        );
+        assert_eq!(
+            tokenized_string(tokens.process(&String::from("銀座1丁目"), &String::from("JP"))),
+            String::from("銀座 1 丁目")
+        );
+        assert_eq!(
+            tokenized_string(tokens.process(&String::from("センター通り"), &String::from("JP"))),
+            String::from("セン ンタ ター ー通 通り")
+        );
+        assert_eq!(
+            tokenized_string(tokens.process(&String::from("ถนนสุขุมวิท"), &String::from("TH"))),
+            String::from("ถน นน นสุ สุขุ ขุม มวิ วิท")
+        );
+        assert_eq!(
+            tokenized_string(
+                tokens.process(&String::from("Nathan Road 彌敦道"), &String::from("HK"))
+            ),
+            String::from("nathan road 彌敦 敦道")
+        );
+
+        // dictionary tokens are kept whole
+        let mut map = HashMap::new();
+        map.insert(
+            String::from("大通"),
+            ParsedToken::new(String::from("大通"), Some(TokenType::Way)),
+        );
+        let tokens = Tokens::new(map, HashMap::new(), HashMap::new()).unwrap();
+        assert_eq!(
+            tokens.process(&String::from("大通"), &String::from("JP")),
+            vec![Tokenized::new(String::from("大通"), Some(TokenType::Way))]
+        );
+    }
+
+    #[test]
+    fn test_load_tokens() {
+        let path = std::env::temp_dir().join("pt2itp-tokens.json");
+        std::fs::write(
+            &path,
+            r#"[
+                { "tokens": ["Carrera", "Cra", "Kr"], "full": "Carrera", "canonical": "Cra", "type": "way" },
+                { "tokens": ["Terrace", "Ter"], "full": "Terrace", "canonical": "Ter", "type": "way" },
+                { "tokens": ["Terrace", "Ter"], "full": "Terrace", "canonical": "Ter", "type": null },
+                { "tokens": ["Kr"], "remove": true }
+            ]"#,
+        )
+        .unwrap();
+
+        let tokens = Tokens::load(None, &path.to_str().unwrap().to_string()).unwrap();
+
+        assert_eq!(
+            tokens.process(&String::from("Carrera 7"), &String::from("CO")),
+            vec![
+                Tokenized::new(String::from("cra"), Some(TokenType::Way)),
+                Tokenized::new(String::from("7"), None),
+            ]
+        );
+        assert_eq!(
+            tokens.process(&String::from("Kr 7"), &String::from("CO")),
+            vec![
+                Tokenized::new(String::from("kr"), None),
+                Tokenized::new(String::from("7"), None),
+            ]
+        );
+        assert_eq!(
+            tokens.process(&String::from("Park Terrace"), &String::from("CO")),
+            vec![
+                Tokenized::new(String::from("park"), None),
+                Tokenized::new(String::from("ter"), None),
+            ]
+        );
+
+        std::fs::write(
+            &path,
+            r#"[{ "tokens": ["Kr"], "canonical": "Cra", "type": "nope" }]"#,
+        )
+        .unwrap();
+        assert!(Tokens::load(None, &path.to_str().unwrap().to_string()).is_err());
+
+        std::fs::write(&path, r#"[{ "tokens": ["Kr"] }]"#).unwrap();
+        assert!(Tokens::load(None, &path.to_str().unwrap().to_string()).is_err());
+
+        std::fs::write(
+            &path,
+            r#"[{ "tokens": ["(kr"], "canonical": "Cra", "regex": true }]"#,
+        )
+        .unwrap();
+        match Tokens::load(None, &path.to_str().unwrap().to_string()) {
+            Err(err) => assert!(err.starts_with("Invalid regex token (kr")),
+            Ok(_) => panic!("invalid regex token should not load"),
+        }
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(Tokens::load(None, &path.to_str().unwrap().to_string()).is_err());
+    }
+
     #[test]
     fn test_de_replacement() {
-        let tokens = Tokens::generate(vec![String::from("de")]);
+        let tokens = Tokens::generate(vec![String::from("de")]).unwrap();
         assert_eq!(
             tokens.process(&String::from("Fresenbergstr"), &String::from("DE")),
             vec![
@@ -511,7 +1054,7 @@ mod tests {
     }
     #[test]
     fn test_multi_word_tokens() {
-        let tokens = Tokens::generate(vec![String::from("es")]);
+        let tokens = Tokens::generate(vec![String::from("es")]).unwrap();
         assert_eq!(
             tokens.process(&String::from("GV Corts Catalanes"), &String::from("ES")),
             vec![
@@ -572,7 +1115,7 @@ mod tests {
 
     #[test]
     fn test_generate_tokens() {
-        let tokens = Tokens::generate(vec![String::from("en")]);
+        let tokens = Tokens::generate(vec![String::from("en")]).unwrap();
 
         assert_eq!(
             tokens.process(&String::from("New Jersey Av NW"), &String::from("US")),
@@ -818,4 +1361,185 @@ mod tests {
             ]
         );
     }
+
+    ///
+    /// Tokens::process as it was before the replacements were compiled: each regex token
+    /// is compiled per name & run in turn in the order of the token map, then each multi
+    /// token is replaced in turn, the name being re-tokenized after every replacement
+    ///
+    fn process_baseline(
+        maps: &TokenMaps,
+        tokens: &Tokens,
+        text: &String,
+        country: &String,
+    ) -> Vec<Tokenized> {
+        let mut words = tokens.tokenize(&text);
+
+        let mut normalized_full_text = diacritics(&text.to_lowercase());
+        if !country.is_empty() && !SKIP_REGEX.contains(&country.as_str()) {
+            for (regex_string, v) in maps.regex_tokens.iter() {
+                let re = Regex::new(regex_string).unwrap();
+                let canonical: &str = &*v.canonical;
+                normalized_full_text = re.replace_all(&normalized_full_text, canonical).to_string();
+                words = tokens.tokenize(&normalized_full_text);
+            }
+            for (multi_string, v) in maps.multi_tokens.iter() {
+                let canonical: &str = &*v.canonical;
+                normalized_full_text = normalized_full_text.replace(multi_string, canonical);
+                words = tokens.tokenize(&normalized_full_text);
+            }
+        }
+
+        tokens.lookup(words, country)
+    }
+
+    #[derive(Deserialize)]
+    struct CorpusGroup {
+        language: String,
+        country: String,
+        names: Vec<String>,
+    }
+
+    ///
+    /// Street names of test/fixtures/tokens-corpus.json along with the token maps of their
+    /// language, the Tokens being compiled from copies of the maps so that both keep the
+    /// same iteration order
+    ///
+    fn corpus() -> Vec<(TokenMaps, Tokens, String, Vec<String>)> {
+        let groups: Vec<CorpusGroup> =
+            serde_json::from_str(include_str!("../../../test/fixtures/tokens-corpus.json"))
+                .unwrap();
+
+        groups
+            .into_iter()
+            .map(|group| {
+                let maps = TokenMaps::import(vec![group.language]).unwrap();
+                let tokens = Tokens::new(
+                    maps.tokens.clone(),
+                    maps.regex_tokens.clone(),
+                    maps.multi_tokens.clone(),
+                )
+                .unwrap();
+
+                (maps, tokens, group.country, group.names)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_replacer() {
+        let mut regex_map: HashMap<String, ParsedToken> = HashMap::new();
+        regex_map.insert(
+            String::from(r"(.+)str$"),
+            ParsedToken::new(String::from("${1} str"), None),
+        );
+        regex_map.insert(
+            String::from(r"^sankt"),
+            ParsedToken::new(String::from("st"), None),
+        );
+
+        let replacer = RegexReplacer::new(&regex_map).unwrap();
+        assert_eq!(
+            replacer.replace_all(String::from("sankt fresenbergstr")),
+            String::from("st fresenberg str")
+        );
+        assert_eq!(
+            replacer.replace_all(String::from("main st")),
+            String::from("main st")
+        );
+
+        regex_map.insert(
+            String::from(r"(kr"),
+            ParsedToken::new(String::from("cra"), None),
+        );
+        match RegexReplacer::new(&regex_map) {
+            Ok(_) => panic!("invalid regex token must be rejected"),
+            Err(err) => assert!(err.starts_with("Invalid regex token (kr")),
+        };
+
+        let mut multi_map: HashMap<String, ParsedToken> = HashMap::new();
+        multi_map.insert(
+            String::from("gran via"),
+            ParsedToken::new(String::from("gv"), None),
+        );
+        multi_map.insert(
+            String::from("gran via de les"),
+            ParsedToken::new(String::from("gv"), None),
+        );
+        multi_map.insert(
+            String::from("a.b"),
+            ParsedToken::new(String::from("ab"), None),
+        );
+
+        // the longest token is replaced where several match at the same position
+        let replacer = MultiReplacer::new(&multi_map);
+        assert_eq!(
+            replacer.replace_all(&String::from("gran via de les corts")),
+            String::from("gv corts")
+        );
+        assert_eq!(
+            replacer.replace_all(&String::from("gran via de colon")),
+            String::from("gv de colon")
+        );
+
+        // multi tokens are not treated as regexes
+        assert_eq!(
+            replacer.replace_all(&String::from("axb a.b")),
+            String::from("axb ab")
+        );
+    }
+
+    #[test]
+    fn test_process_baseline() {
+        for (maps, tokens, country, names) in corpus() {
+            for name in names {
+                assert_eq!(
+                    tokens.process(&name, &country),
+                    process_baseline(&maps, &tokens, &name, &country),
+                    "{} {}",
+                    country,
+                    name
+                );
+            }
+        }
+    }
+
+    ///
+    /// The compiled replacements must be faster than compiling & running each in turn
+    ///
+    /// npm run bench
+    ///
+    #[test]
+    #[ignore]
+    fn bench_process() {
+        let iterations = 100;
+
+        let mut baseline_time = std::time::Duration::new(0, 0);
+        let mut compiled_time = std::time::Duration::new(0, 0);
+
+        for (maps, tokens, country, names) in corpus() {
+            let start = std::time::Instant::now();
+            for _ in 0..iterations {
+                for name in &names {
+                    process_baseline(&maps, &tokens, name, &country);
+                }
+            }
+            baseline_time += start.elapsed();
+
+            let start = std::time::Instant::now();
+            for _ in 0..iterations {
+                for name in &names {
+                    tokens.process(name, &country);
+                }
+            }
+            compiled_time += start.elapsed();
+        }
+
+        assert!(
+            compiled_time < baseline_time,
+            "compiled {:?} is not faster than baseline {:?}",
+            compiled_time,
+            baseline_time
+        );
+    }
 }
@@ -5,7 +5,7 @@ use regex::{Regex, RegexSet};
 use crate::{hecate, types::name::InputName, Context, Name, Names, Source};
 
 /// A representation of a single Address
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Address {
     /// An optional identifier for the address
     pub id: Option<i64>,
@@ -216,7 +216,16 @@ impl Address {
     }
 
     pub fn std(&mut self, country: &String) -> Result<(), String> {
-        self.number = self.number.to_lowercase();
+        self.number = Address::std_number(&self.number, country)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Standardize an address number, ie: "10 A" => "10a", erroring on unsupported numbers
+    ///
+    pub fn std_number(number: &String, country: &String) -> Result<String, String> {
+        let mut number = number.to_lowercase();
 
         lazy_static! {
             static ref HALF: Regex = Regex::new(r"\s1/2$").unwrap();
@@ -240,35 +249,42 @@ impl Address {
             .unwrap();
             static ref SLASH_EXCLUDED_COUNTRIES: Vec<String> =
                 vec![String::from("pl"), String::from("cz"), String::from("sk")];
+            static ref JP_SUPPORTED: RegexSet =
+                RegexSet::new(&[r"^\d+[a-z]?$", r"^\d+(-\d+){1,2}$"]).unwrap();
         };
 
         // Remove 1/2 Numbers from addresses as they are not currently supported
-        self.number = HALF.replace(self.number.as_str(), "").to_string();
+        number = HALF.replace(number.as_str(), "").to_string();
 
         // Transform '123 B' = '123B' so it is supported
-        self.number = UNIT.replace(self.number.as_str(), "$num$unit").to_string();
+        number = UNIT.replace(number.as_str(), "$num$unit").to_string();
+
+        // Japan uses block numbering, ie: "1丁目2番3号" (chome-ban-go) = "1-2-3"
+        if country == "jp" {
+            number = jp_block(&number);
+        }
 
         // Czech Republic and Poland have addresses in the format of "123/89"
         // Let's allow those through, but still not 123 1/2, regardless of country
-        let supported = if SLASH_EXCLUDED_COUNTRIES.contains(&country) {
+        let supported = if country == "jp" {
+            &JP_SUPPORTED
+        } else if SLASH_EXCLUDED_COUNTRIES.contains(&country) {
             &SLASH_SUPPORTED
         } else {
             &*DEFAULT_SUPPORTED
         };
-        if !supported.is_match(self.number.as_str()) {
-            let return_string = format!(
-                "Number is not a supported address/unit type: {:?}",
-                self.number
-            );
+        if !supported.is_match(number.as_str()) {
+            let return_string =
+                format!("Number is not a supported address/unit type: {:?}", number);
             return Err(return_string);
         }
 
-        if self.number.len() > 10 {
-            let return_string = format!("Number should not exceed 10 chars: {:?}", self.number);
+        if number.len() > 10 {
+            let return_string = format!("Number should not exceed 10 chars: {:?}", number);
             return Err(return_string);
         }
 
-        Ok(())
+        Ok(number)
     }
 
     ///
@@ -524,9 +540,36 @@ fn get_interpolate(map: &mut serde_json::Map<String, serde_json::Value>) -> Resu
     }
 }
 
+///
+/// Normalize Japanese block numbers to their hyphenated form
+/// ie: "1丁目2番3号" => "1-2-3", "２番地３" => "2-3"
+///
+fn jp_block(number: &String) -> String {
+    lazy_static! {
+        static ref SEPARATOR: Regex = Regex::new(r"(丁目|番地|番|の|[－‐−ー-])+").unwrap();
+    }
+
+    // Full width digits are common in Japanese source data
+    let number: String = number
+        .chars()
+        .map(|c| match c {
+            '０'..='９' => std::char::from_u32(c as u32 - '０' as u32 + '0' as u32).unwrap_or(c),
+            _ => c,
+        })
+        .collect();
+
+    let number = number.trim().trim_end_matches('号');
+
+    SEPARATOR
+        .replace_all(number, "-")
+        .trim_matches('-')
+        .to_string()
+}
+
 mod tests {
     use super::*;
     use crate::Tokens;
+    use std::collections::HashMap;
 
     #[test]
     fn test_address_simple_geom() {
@@ -537,7 +580,7 @@ mod tests {
             let context = Context::new(
                 String::from("us"),
                 Some(String::from("mn")),
-                Tokens::generate(vec![String::from("en")]),
+                Tokens::generate(vec![String::from("en")]).unwrap(),
             );
 
             let addr = Address::new(feat, &context).unwrap();
@@ -552,7 +595,7 @@ mod tests {
             let context = Context::new(
                 String::from("us"),
                 Some(String::from("mn")),
-                Tokens::generate(vec![String::from("en")]),
+                Tokens::generate(vec![String::from("en")]).unwrap(),
             );
 
             let addr = Address::new(feat, &context).unwrap();
@@ -567,7 +610,7 @@ mod tests {
             let context = Context::new(
                 String::from("pl"),
                 None,
-                Tokens::generate(vec![String::from("pl")]),
+                Tokens::generate(vec![String::from("pl")]).unwrap(),
             );
 
             let addr = Address::new(feat, &context).unwrap();
@@ -576,6 +619,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_address_std_jp() {
+        let context = Context::new(
+            String::from("jp"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        for (number, expected) in vec![
+            ("12", Ok(String::from("12"))),
+            ("1-2-3", Ok(String::from("1-2-3"))),
+            ("1丁目2番3号", Ok(String::from("1-2-3"))),
+            ("２番地３", Ok(String::from("2-3"))),
+            ("5番", Ok(String::from("5"))),
+            ("1-2-3-4", Err(())),
+        ] {
+            let feat: geojson::GeoJson = format!(r#"{{"type":"Feature","properties":{{"street":"銀座通り","number":"{}","source":"jp"}},"geometry":{{"type":"Point","coordinates":[139.7671,35.6712]}}}}"#, number).parse().unwrap();
+
+            let addr = Address::new(feat, &context);
+
+            assert_eq!(addr.map(|addr| addr.number).map_err(|_| ()), expected);
+        }
+    }
+
     #[test]
     fn test_address_simple_geom_fail() {
         // US street value is has a `/`
@@ -585,7 +652,7 @@ mod tests {
             let context = Context::new(
                 String::from("us"),
                 Some(String::from("mn")),
-                Tokens::generate(vec![String::from("en")]),
+                Tokens::generate(vec![String::from("en")]).unwrap(),
             );
 
             let addr = Address::new(feat, &context);
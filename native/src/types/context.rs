@@ -1,11 +1,22 @@
-use crate::text::Tokens;
+use crate::text::{Registry, Tokens};
+use crate::types::Policy;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct InputContext {
     pub country: Option<String>,
     pub region: Option<String>,
     pub languages: Option<Vec<String>>,
+
+    /// Path to a JSON file of declarative synonym rules
+    pub synonyms: Option<String>,
+
+    /// Path to a JSON file of custom token groups, merged over the language tokens
+    pub tokens: Option<String>,
+
+    /// Path to a JSON file of name ranking policy overrides
+    pub policy: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -13,30 +24,54 @@ pub struct Context {
     pub country: String,
     pub region: Option<String>,
     pub tokens: Tokens,
+    pub synonyms: Registry,
+    pub policy: Policy,
 }
 
-impl From<InputContext> for Context {
-    fn from(input: InputContext) -> Self {
+impl TryFrom<InputContext> for Context {
+    type Error = String;
+
+    ///
+    /// Build the context of an input context, erroring if any of
+    /// its tokens, synonyms or policy files cannot be loaded
+    ///
+    fn try_from(input: InputContext) -> Result<Self, Self::Error> {
         let country = input.country.unwrap_or(String::from(""));
         let region = input.region;
-        let tokens = match input.languages {
-            None => Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
-            Some(languages) => Tokens::generate(languages),
+        let tokens = match (input.languages, input.tokens) {
+            (None, None) => Tokens::default(),
+            (Some(languages), None) => Tokens::generate(languages)?,
+            (languages, Some(path)) => Tokens::load(languages, &path)?,
         };
 
-        Context::new(country, region, tokens)
+        let mut context = Context::new(country, region, tokens);
+
+        if let Some(synonyms) = input.synonyms {
+            context.synonyms.load(&synonyms)?;
+        }
+
+        if let Some(policy) = input.policy {
+            context.policy = Policy::load(&context.country, &context.region, &policy)?;
+        }
+
+        Ok(context)
     }
 }
 
 impl Context {
     pub fn new(country: String, region: Option<String>, tokens: Tokens) -> Self {
+        let country = country.to_uppercase();
+        let policy = Policy::new(&country);
+
         Context {
-            country: country.to_uppercase(),
+            country: country,
             region: match region {
                 None => None,
                 Some(region) => Some(region.to_uppercase()),
             },
             tokens: tokens,
+            synonyms: Registry::default(),
+            policy: policy,
         }
     }
 
@@ -148,15 +183,13 @@ mod tests {
     #[test]
     fn context_test() {
         assert_eq!(
-            Context::new(
-                String::from("us"),
-                None,
-                Tokens::new(HashMap::new(), HashMap::new(), HashMap::new())
-            ),
+            Context::new(String::from("us"), None, Tokens::default()),
             Context {
                 country: String::from("US"),
                 region: None,
-                tokens: Tokens::new(HashMap::new(), HashMap::new(), HashMap::new())
+                tokens: Tokens::default(),
+                synonyms: Registry::default(),
+                policy: Policy::new("US")
             }
         );
 
@@ -164,23 +197,67 @@ mod tests {
             Context::new(
                 String::from("uS"),
                 Some(String::from("wv")),
-                Tokens::new(HashMap::new(), HashMap::new(), HashMap::new())
+                Tokens::default()
             ),
             Context {
                 country: String::from("US"),
                 region: Some(String::from("WV")),
-                tokens: Tokens::new(HashMap::new(), HashMap::new(), HashMap::new())
+                tokens: Tokens::default(),
+                synonyms: Registry::default(),
+                policy: Policy::new("US")
             }
         );
 
         let cntx = Context::new(
             String::from("uS"),
             Some(String::from("wv")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::default(),
         );
 
         assert_eq!(cntx.region_code(), Some(String::from("US-WV")));
 
         assert_eq!(cntx.region_name(), Some(String::from("West Virginia")));
     }
+
+    #[test]
+    fn context_try_from_test() {
+        let input = || InputContext {
+            country: Some(String::from("us")),
+            region: None,
+            languages: None,
+            synonyms: None,
+            tokens: None,
+            policy: None,
+        };
+
+        let context = Context::try_from(input()).unwrap();
+        assert_eq!(context.country, String::from("US"));
+
+        let missing = std::env::temp_dir()
+            .join("pt2itp-missing-context.json")
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let err = Context::try_from(InputContext {
+            tokens: Some(missing.clone()),
+            ..input()
+        })
+        .unwrap_err();
+        assert!(err.starts_with("Unable to open tokens file"));
+
+        let err = Context::try_from(InputContext {
+            synonyms: Some(missing.clone()),
+            ..input()
+        })
+        .unwrap_err();
+        assert!(err.starts_with("Unable to open synonyms file"));
+
+        let err = Context::try_from(InputContext {
+            policy: Some(missing),
+            ..input()
+        })
+        .unwrap_err();
+        assert!(err.starts_with("Unable to open policy file"));
+    }
 }
@@ -5,6 +5,7 @@ mod polygon;
 pub mod context;
 pub mod hecate;
 pub mod name;
+pub mod policy;
 
 pub use self::address::Address;
 pub use self::network::Network;
@@ -15,3 +16,5 @@ pub use self::context::InputContext;
 pub use self::name::Name;
 pub use self::name::Names;
 pub use self::name::Source;
+pub use self::policy::InputPolicy;
+pub use self::policy::Policy;
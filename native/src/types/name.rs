@@ -1,6 +1,6 @@
 use crate::text::titlecase;
 use crate::Tokenized;
-use crate::{text, Context};
+use crate::{text, Context, Policy};
 use geocoder_abbreviations::TokenType;
 use std::collections::HashMap;
 
@@ -15,6 +15,10 @@ pub struct InputName {
 
     /// When choosing which street name is primary, order by priority
     pub priority: i8,
+
+    /// ISO 639-1 language code of the name, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 impl From<Name> for InputName {
@@ -22,6 +26,7 @@ impl From<Name> for InputName {
         InputName {
             display: name.display,
             priority: name.priority,
+            language: name.language,
         }
     }
 }
@@ -41,36 +46,21 @@ impl Names {
 
         let mut synonyms: Vec<Name> = Vec::new();
 
-        if context.country == String::from("US") {
-            for name in names.names.iter_mut() {
-                if name.source == Some(Source::Network) {
-                    synonyms.append(&mut text::syn_number_suffix(&name, &context));
-                    synonyms.append(&mut text::syn_written_numeric(&name, &context));
-                    synonyms.append(&mut text::syn_state_hwy(&name, &context));
-                    synonyms.append(&mut text::syn_us_hwy(&name, &context));
-                    synonyms.append(&mut text::syn_us_cr(&name, &context));
-                    synonyms.append(&mut text::syn_us_famous(&name, &context));
-
-                    if context.region.is_some() && context.region.as_ref().unwrap() == "NY" {
-                        synonyms.append(&mut text::syn_ny_beach(&name, &context));
-                    }
-                }
-            }
-        } else if context.country == String::from("CA") {
-            for name in names.names.iter_mut() {
-                if name.source == Some(Source::Network) {
-                    synonyms.append(&mut text::syn_ca_hwy(&name, &context));
-
-                    if context.region.is_some() && context.region.as_ref().unwrap() == "QC" {
-                        synonyms.append(&mut text::syn_ca_french(&name, &context));
-                    }
+        for name in names.names.iter() {
+            if name.source == Some(Source::Network) {
+                // synonyms are in the same language as the name they are generated from
+                for synonym in context.synonyms.generate(&name, &context) {
+                    synonyms.push(synonym.set_language(name.language.clone()));
                 }
             }
         }
+        for synonym in synonyms.iter_mut() {
+            synonym.priority += context.policy.source(&synonym.source);
+        }
         names.names.append(&mut synonyms);
         names.empty();
-        names.sort();
-        names.dedupe();
+        names.sort_with(&context.policy);
+        names.dedupe(&context.country);
 
         names
     }
@@ -79,7 +69,9 @@ impl Names {
         let mut full_names: Vec<Name> = Vec::with_capacity(names.len());
 
         for name in names {
-            full_names.push(Name::new(name.display, name.priority, None, &context));
+            full_names.push(
+                Name::new(name.display, name.priority, None, &context).set_language(name.language),
+            );
         }
 
         Names::new(full_names, &context)
@@ -100,6 +92,7 @@ impl Names {
                     vec![InputName {
                         display: street.as_str().unwrap().to_string(),
                         priority: 0,
+                        language: None,
                     }]
                 } else {
                     match serde_json::from_value(street) {
@@ -109,6 +102,13 @@ impl Names {
                         }
                     }
                 };
+                // adjust the priority of names by their source, lowering address features by default
+                // and by their language, when a language is preferred for the region
+                for name in names.iter_mut() {
+                    name.priority += context.policy.source(&source);
+                    name.priority += context.policy.language(&name.language);
+                }
+
                 // network features must have a name with a higher priority than alternative names
                 if source == Some(Source::Network) && names.len() > 1 {
                     if names[0].priority == names[1].priority {
@@ -116,16 +116,12 @@ impl Names {
                     }
                 }
 
-                // lower the priority of names on address features
-                if source == Some(Source::Address) {
-                    for name in names.iter_mut() {
-                        name.priority -= 1;
-                    }
-                }
-
                 let names: Vec<Name> = names
                     .into_iter()
-                    .map(|name| Name::new(name.display, name.priority, source.clone(), &context))
+                    .map(|name| {
+                        Name::new(name.display, name.priority, source.clone(), &context)
+                            .set_language(name.language)
+                    })
                     .collect();
 
                 names
@@ -165,12 +161,12 @@ impl Names {
     }
 
     ///
-    /// Dedupe a Names struct based on the tokenized version of each name.
+    /// Dedupe a Names struct based on the tokenized version of each name (see dedupe_key).
     /// Names with the same priority and tokenized name will preference the duplicate with the
     /// longest display name. This tries to preface non-abbreviated synonyms where they exist,
     /// e.g. 'East Main Street' rather than 'E Main St'
     ///
-    pub fn dedupe(&mut self) {
+    pub fn dedupe(&mut self, country: &str) {
         struct Dedupe {
             name: Name,
             first_index: usize,
@@ -185,7 +181,7 @@ impl Names {
         let old_names: Vec<Name> = std::mem::replace(&mut self.names, Vec::new());
 
         for (i, name) in old_names.into_iter().enumerate() {
-            match tokenized.get_mut(&name.tokenized_string()) {
+            match tokenized.get_mut(&name.dedupe_key(country)) {
                 // if the tokenized name already exists
                 Some(d) => {
                     // if the existing name is generated, don't overwrite
@@ -193,22 +189,30 @@ impl Names {
                         continue;
                     // if the new name is generated or had a longer, potentially unabbreviated form,
                     // overwrite the entire Name, keeping the existing priority and freq values
+                    // and the existing language if the new name has none
                     } else if name.source == Some(Source::Generated)
                         || name.display.len() > d.name.display.len()
                     {
                         let priority = d.name.priority;
                         let freq = d.name.freq + name.freq;
+                        let language = d.name.language.take();
                         d.name = name;
                         d.name.priority = priority;
                         d.name.freq = freq;
+                        if d.name.language.is_none() {
+                            d.name.language = language;
+                        }
                     } else {
                         // Keep track of frequency
                         d.name.freq = d.name.freq + name.freq;
+                        if d.name.language.is_none() {
+                            d.name.language = name.language;
+                        }
                     }
                 }
                 // if it doesn't yet exist, add it
                 None => {
-                    tokenized.insert(name.tokenized_string(), Dedupe::new(name, i));
+                    tokenized.insert(name.dedupe_key(country), Dedupe::new(name, i));
                 }
             }
         }
@@ -221,10 +225,22 @@ impl Names {
     /// Sort Names struct by priority and frequency
     ///
     pub fn sort(&mut self) {
+        self.sort_with(&Policy::default());
+    }
+
+    ///
+    /// Sort Names struct by the score of the given ranking policy, then frequency
+    ///
+    pub fn sort_with(&mut self, policy: &Policy) {
+        let total_freq: i64 = self.names.iter().map(|name| name.freq).sum();
+
         self.names.sort_by(|a, b| {
-            if a.priority > b.priority {
+            let a_score = policy.score(a, total_freq);
+            let b_score = policy.score(b, total_freq);
+
+            if a_score > b_score {
                 std::cmp::Ordering::Less
-            } else if a.priority < b.priority {
+            } else if a_score < b_score {
                 std::cmp::Ordering::Greater
             } else {
                 if a.freq > b.freq {
@@ -242,15 +258,22 @@ impl Names {
     /// Filter outlier names
     ///
     pub fn filter_outliers(&mut self) {
+        self.filter_outliers_with(&Policy::default());
+    }
+
+    ///
+    /// Filter outlier names using the thresholds of the given ranking policy
+    ///
+    pub fn filter_outliers_with(&mut self, policy: &Policy) {
         let total_freq: i64 = self.names.iter().map(|name| name.freq).sum();
-        // Only filter when there are more than 10 addresses in the cluster
-        if total_freq > 10 {
+        // Only filter when there are more than 10 addresses in the cluster by default
+        if total_freq > policy.outlier_min_freq {
             let mut temp_names: Vec<Name> = self.names.clone();
-            // Remove names that represent 8% or less of names in the cluster
+            // Remove names that represent 8% or less of names in the cluster by default
             // and only if their source is from an address point
             temp_names.retain(|name| {
                 name.source != Some(Source::Address)
-                    || (name.freq as f32 / total_freq as f32) > 0.08
+                    || (name.freq as f32 / total_freq as f32) > policy.outlier_ratio
                         && name.source == Some(Source::Address)
             });
             if temp_names.len() > 0 {
@@ -296,6 +319,10 @@ pub struct Name {
 
     /// Frequency of the given name
     pub freq: i64,
+
+    /// ISO 639-1 language code of the name, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
@@ -329,10 +356,11 @@ impl Name {
 
         if context.country == String::from("US") || context.country == String::from("CA") {
             display = text::str_remove_octo(&display);
-            // penalize less desireable street names
-            if text::is_undesireable(&tokenized) {
-                priority -= 1;
-            }
+        }
+
+        // penalize less desireable street names
+        if context.policy.is_undesireable(&tokenized) {
+            priority -= context.policy.undesirable_penalty;
         }
 
         Name {
@@ -341,6 +369,7 @@ impl Name {
             source: source,
             tokenized: tokenized,
             freq: 1,
+            language: None,
         }
     }
 
@@ -370,6 +399,19 @@ impl Name {
         self
     }
 
+    ///
+    /// Builder style language setter, normalizing the language code to lowercase
+    ///
+    /// ie:
+    /// Name::new().set_language(Some(String::from("fr")))
+    ///
+    /// Can be chained with other builder functions
+    ///
+    pub fn set_language(mut self, language: Option<String>) -> Self {
+        self.language = language.map(|language| language.trim().to_lowercase());
+        self
+    }
+
     ///
     /// Tokenize the name object and return it as a string
     ///
@@ -381,6 +423,30 @@ impl Name {
         tokenized
     }
 
+    ///
+    /// Key names are deduped on, the tokenized name suffixed with "ß" and "-" if the
+    /// display contains them. Tokenizing folds "ß" into "ss" & hyphens into spaces so
+    /// the key keeps both spellings of a name apart ("Große Gasse" & "Grosse Gasse",
+    /// "Rue Saint-Jean" & "Rue Saint Jean")
+    ///
+    /// The suffixes only apply to the countries where both spellings are generated,
+    /// "ß" to German & "-" to French speaking countries. Elsewhere names are deduped
+    /// on their tokenized name alone
+    ///
+    pub fn dedupe_key(&self, country: &str) -> String {
+        let mut key = self.tokenized_string();
+
+        if ["DE", "AT", "CH"].contains(&country) && self.display.to_lowercase().contains('ß') {
+            key.push('ß');
+        }
+
+        if ["FR", "BE", "LU"].contains(&country) && self.display.contains('-') {
+            key.push('-');
+        }
+
+        key
+    }
+
     ///
     /// Return a String representation of a Name
     /// object with all known tokens removed
@@ -442,7 +508,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         assert_eq!(
@@ -456,7 +522,8 @@ mod tests {
                     Tokenized::new(String::from("st"), Some(TokenType::Way)),
                     Tokenized::new(String::from("nw"), None)
                 ],
-                freq: 1
+                freq: 1,
+                language: None
             }
         );
 
@@ -471,7 +538,8 @@ mod tests {
                     Tokenized::new(String::from("12"), None),
                     Tokenized::new(String::from("west"), None)
                 ],
-                freq: 1
+                freq: 1,
+                language: None
             }
         );
 
@@ -493,7 +561,8 @@ mod tests {
                     Tokenized::new(String::from("ext"), None),
                     Tokenized::new(String::from("1"), None)
                 ],
-                freq: 1
+                freq: 1,
+                language: None
             }
         );
 
@@ -504,7 +573,8 @@ mod tests {
                 priority: 0,
                 source: None,
                 tokenized: vec![],
-                freq: 1
+                freq: 1,
+                language: None
             }
         );
 
@@ -515,7 +585,8 @@ mod tests {
                 priority: 0,
                 source: None,
                 tokenized: vec![],
-                freq: 1
+                freq: 1,
+                language: None
             }
         );
     }
@@ -525,7 +596,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         let mut names = Names {
@@ -573,12 +644,57 @@ mod tests {
         assert_eq!(names, names_sorted);
     }
 
+    #[test]
+    fn test_names_sort_with() {
+        let mut context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        context.policy.freq_weight = 2.0;
+        context.policy.undesirable = vec![String::from("spur")];
+
+        assert_eq!(
+            Name::new(String::from("Main St Spur"), 0, None, &context).priority,
+            -1
+        );
+        assert_eq!(
+            Name::new(String::from("Main St Ext"), 0, None, &context).priority,
+            0
+        );
+
+        let mut names = Names {
+            names: vec![
+                Name::new(String::from("Main St"), 0, None, &context),
+                Name::new(String::from("Oak St"), -1, None, &context).set_freq(9),
+            ],
+        };
+
+        // Oak St: -1 + 2.0 * 0.9 outranks Main St: 0 + 2.0 * 0.1
+        names.sort_with(&context.policy);
+
+        let mut names_sorted = Names {
+            names: vec![
+                Name::new(String::from("Oak St"), -1, None, &context).set_freq(9),
+                Name::new(String::from("Main St"), 0, None, &context),
+            ],
+        };
+
+        assert_eq!(names, names_sorted);
+
+        // the default policy ignores frequency unless priorities are equal
+        names.sort();
+        names_sorted.names.reverse();
+        assert_eq!(names, names_sorted);
+    }
+
     #[test]
     fn test_names_concat() {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         let mut names = Names {
@@ -611,7 +727,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         // deduping does not sort by priority and frequency-- must call .sort() first
@@ -625,7 +741,7 @@ mod tests {
                 Name::new(String::from("hwy 1"), 1, None, &context),
             ],
         };
-        names.dedupe();
+        names.dedupe(&context.country);
         let names_deduped = Names {
             names: vec![
                 Name::new(String::from("highway 3"), -1, None, &context).set_freq(2),
@@ -648,7 +764,7 @@ mod tests {
                 Name::new(String::from("E Main St"), 0, None, &context),
             ],
         };
-        names.dedupe();
+        names.dedupe(&context.country);
         let names_deduped = Names {
             names: vec![Name::new(
                 String::from("E Main Street"),
@@ -672,7 +788,7 @@ mod tests {
                 ),
             ],
         };
-        names.dedupe();
+        names.dedupe(&context.country);
         let names_deduped = Names {
             names: vec![Name::new(
                 String::from("E Main Street"),
@@ -717,7 +833,7 @@ mod tests {
                 .set_freq(1),
             ],
         };
-        names.dedupe();
+        names.dedupe(&context.country);
         let names_deduped = Names {
             names: vec![
                 Name::new(
@@ -744,6 +860,39 @@ mod tests {
             ],
         };
         assert_eq!(names, names_deduped);
+
+        // ß & ss spellings are only kept apart in German speaking countries
+        let names = Names {
+            names: vec![
+                Name::new(String::from("Große Gasse"), 0, None, &context),
+                Name::new(String::from("Grosse Gasse"), -1, None, &context),
+            ],
+        };
+
+        let mut names_us = names.clone();
+        names_us.dedupe(&context.country);
+        assert_eq!(names_us.names.len(), 1);
+
+        let mut names_de = names.clone();
+        names_de.dedupe("DE");
+        assert_eq!(names_de.names.len(), 2);
+
+        // Hyphen & space spellings are only kept apart in French speaking countries
+        let names = Names {
+            names: vec![
+                Name::new(String::from("Winston-Salem Rd"), 0, None, &context),
+                Name::new(String::from("Winston Salem Rd"), -1, None, &context),
+            ],
+        };
+
+        let mut names_us = names.clone();
+        names_us.dedupe(&context.country);
+        assert_eq!(names_us.names.len(), 1);
+        assert_eq!(names_us.names[0].display, String::from("Winston-Salem Rd"));
+
+        let mut names_fr = names.clone();
+        names_fr.dedupe("FR");
+        assert_eq!(names_fr.names.len(), 2);
     }
 
     #[test]
@@ -751,7 +900,7 @@ mod tests {
         let mut context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         // Name not filtered if source is not Address
@@ -823,7 +972,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         let expected = Names::new(
@@ -901,13 +1050,13 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "1 network synonym must have greater priority: [InputName { display: \"Main St\", priority: -1 }, InputName { display: \"E Main St\", priority: -1 }]"
+        expected = "1 network synonym must have greater priority: [InputName { display: \"Main St\", priority: -1, language: None }, InputName { display: \"E Main St\", priority: -1, language: None }]"
     )]
     fn test_names_from_value_invalid_priority() {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         let _names = Names::from_value(
@@ -923,12 +1072,138 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_names_from_value_language_priority() {
+        let mut context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        // the language penalty is applied before comparing priorities
+        context.policy.language = Some(String::from("en"));
+
+        assert_eq!(
+            Names::from_value(
+                Some(json!([{
+                    "display": "Main St",
+                    "priority": 0,
+                    "language": "en"
+                }, {
+                    "display": "Rue Principale",
+                    "priority": 0,
+                    "language": "fr"
+                }])),
+                Some(Source::Network),
+                &context,
+            )
+            .is_ok(),
+            true
+        );
+    }
+
+    #[test]
+    fn test_names_from_value_language() {
+        let mut context = Context::new(
+            String::from("ca"),
+            Some(String::from("qc")),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        let names = Names::from_value(
+            Some(json!([{
+                "display": "Rue Principale",
+                "priority": 1,
+                "language": "FR"
+            }, {
+                "display": "Main St",
+                "priority": 0,
+                "language": "en"
+            }])),
+            Some(Source::Network),
+            &context,
+        )
+        .unwrap();
+
+        let names: Vec<(String, i8, Option<String>)> = names
+            .names
+            .into_iter()
+            .map(|name| (name.display, name.priority, name.language))
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                (String::from("Rue Principale"), 1, Some(String::from("fr"))),
+                (String::from("Main St"), 0, Some(String::from("en")))
+            ]
+        );
+
+        // names in another language than the preferred language are penalized
+        context.policy.language = Some(String::from("fr"));
+
+        let names = Names::from_value(
+            Some(json!([{
+                "display": "Main St",
+                "priority": 0,
+                "language": "en"
+            }, {
+                "display": "Rue Principale",
+                "priority": 0,
+                "language": "fr"
+            }, {
+                "display": "Highway 20",
+                "priority": 0
+            }])),
+            Some(Source::Address),
+            &context,
+        )
+        .unwrap();
+
+        let names: Vec<(String, i8)> = names
+            .names
+            .into_iter()
+            .map(|name| (name.display, name.priority))
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                (String::from("Rue Principale"), -1),
+                (String::from("Highway 20"), -1),
+                (String::from("Main St"), -2)
+            ]
+        );
+
+        // generated synonyms keep the language of their name
+        let context = Context::new(
+            String::from("us"),
+            None,
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
+        );
+
+        let names = Names::from_value(
+            Some(json!([{
+                "display": "5 Avenue",
+                "priority": 0,
+                "language": "en"
+            }])),
+            Some(Source::Network),
+            &context,
+        )
+        .unwrap();
+
+        assert_eq!(names.names.len(), 2);
+        assert_eq!(names.names[1].source, Some(Source::Generated));
+        assert_eq!(names.names[1].language, Some(String::from("en")));
+    }
+
     #[test]
     fn test_names_has_diff() {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         let a_name = Names::new(vec![Name::new("Main St", 0, None, &context)], &context);
@@ -975,7 +1250,7 @@ mod tests {
         let mut context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         assert_eq!(Names::new(vec![], &context), Names { names: Vec::new() });
@@ -1219,7 +1494,7 @@ mod tests {
         context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         assert_eq!(
@@ -1334,7 +1609,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         assert_eq!(
@@ -1352,7 +1627,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         assert_eq!(
@@ -1374,7 +1649,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         assert_eq!(
@@ -1403,7 +1678,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::generate(vec![String::from("en")]),
+            Tokens::generate(vec![String::from("en")]).unwrap(),
         );
 
         assert_eq!(
@@ -1445,7 +1720,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         let mut empty_a = Names::new(
@@ -170,7 +170,7 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             Some(String::from("dc")),
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         let net = Network::new(feat, &context).unwrap();
@@ -180,13 +180,13 @@ mod tests {
 
     #[test]
     #[should_panic(
-        expected = "1 network synonym must have greater priority: [InputName { display: \"Main St\", priority: -1 }, InputName { display: \"E Main St\", priority: -1 }]"
+        expected = "1 network synonym must have greater priority: [InputName { display: \"Main St\", priority: -1, language: None }, InputName { display: \"E Main St\", priority: -1, language: None }]"
     )]
     fn test_network_invalid_priority() {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()),
+            Tokens::new(HashMap::new(), HashMap::new(), HashMap::new()).unwrap(),
         );
 
         let feat: geojson::GeoJson = String::from(r#"{
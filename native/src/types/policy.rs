@@ -0,0 +1,367 @@
+use crate::text::UNDESIREABLE;
+use crate::{Name, Source, Tokenized};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::BufReader;
+
+///
+/// InputPolicy is only used to read a ranking policy file, any missing
+/// values fall back to the default policy of the country
+///
+/// {
+///     "sources": { "address": -1, "network": 0, "generated": 0 },
+///     "freq_weight": 0.0,
+///     "undesirable": ["ext", "connector"],
+///     "undesirable_penalty": 1,
+///     "outlier_ratio": 0.08,
+///     "outlier_min_freq": 10,
+///     "languages": { "CA": "en", "CA-QC": "fr" },
+///     "language_penalty": 1
+/// }
+///
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct InputPolicy {
+    pub sources: Option<InputPolicySources>,
+    pub freq_weight: Option<f64>,
+    pub undesirable: Option<Vec<String>>,
+    pub undesirable_penalty: Option<i8>,
+    pub outlier_ratio: Option<f32>,
+    pub outlier_min_freq: Option<i64>,
+
+    /// Preferred language by country or region code, the region taking precedence
+    pub languages: Option<HashMap<String, String>>,
+    pub language_penalty: Option<i8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default)]
+pub struct InputPolicySources {
+    pub address: Option<i8>,
+    pub network: Option<i8>,
+    pub generated: Option<i8>,
+}
+
+///
+/// Ranking policy deciding the priority & order of names,
+/// and so which name ends up as the primary carmen:text
+///
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct Policy {
+    /// Priority added to names from address features
+    pub address: i8,
+
+    /// Priority added to names from network features
+    pub network: i8,
+
+    /// Priority added to generated synonyms, on top of their generator's priority
+    pub generated: i8,
+
+    /// Weight of a name's share of the total frequency, added to its priority when sorting
+    pub freq_weight: f64,
+
+    /// Tokens making a name less desireable, ie: US Hwy 125 Ext 1
+    pub undesirable: Vec<String>,
+
+    /// Priority removed from less desireable names
+    pub undesirable_penalty: i8,
+
+    /// Address names making up this share or less of all names are outliers
+    pub outlier_ratio: f32,
+
+    /// Outliers are only filtered when the total frequency exceeds this
+    pub outlier_min_freq: i64,
+
+    /// Preferred language of names in the country or region
+    pub language: Option<String>,
+
+    /// Priority removed from names in another language than the preferred language
+    pub language_penalty: i8,
+}
+
+impl Policy {
+    ///
+    /// Default policy of a given country
+    ///
+    pub fn new(country: &str) -> Self {
+        let undesirable = match country {
+            "US" | "CA" => UNDESIREABLE.iter().map(|token| token.to_string()).collect(),
+            _ => Vec::new(),
+        };
+
+        Policy {
+            address: -1,
+            network: 0,
+            generated: 0,
+            freq_weight: 0.0,
+            undesirable: undesirable,
+            undesirable_penalty: 1,
+            outlier_ratio: 0.08,
+            outlier_min_freq: 10,
+            language: None,
+            language_penalty: 1,
+        }
+    }
+
+    ///
+    /// Load a policy file, falling back to the default policy
+    /// of the given country for any value it does not set
+    ///
+    pub fn load(country: &str, region: &Option<String>, path: &String) -> Result<Self, String> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) => {
+                return Err(format!("Unable to open policy file: {}", err));
+            }
+        };
+
+        let input: InputPolicy = match serde_json::from_reader(BufReader::new(file)) {
+            Ok(input) => input,
+            Err(err) => {
+                return Err(format!("Invalid policy file: {}", err));
+            }
+        };
+
+        Ok(Policy::resolve(country, region, input))
+    }
+
+    ///
+    /// Policy of the given country & region, falling back to the default
+    /// policy of the country for any value the input policy does not set
+    ///
+    pub fn resolve(country: &str, region: &Option<String>, input: InputPolicy) -> Self {
+        let language = match input.languages {
+            None => None,
+            Some(ref languages) => {
+                let region = match region {
+                    Some(region) => languages.get(&format!("{}-{}", country, region)),
+                    None => None,
+                };
+
+                region.or(languages.get(country)).cloned()
+            }
+        };
+
+        let mut policy = Policy::new(country).merge(input);
+        policy.language = language.map(|language| language.to_lowercase());
+
+        policy
+    }
+
+    ///
+    /// Override the values of the policy that are set by the input policy
+    ///
+    pub fn merge(mut self, input: InputPolicy) -> Self {
+        if let Some(sources) = input.sources {
+            self.address = sources.address.unwrap_or(self.address);
+            self.network = sources.network.unwrap_or(self.network);
+            self.generated = sources.generated.unwrap_or(self.generated);
+        }
+
+        self.freq_weight = input.freq_weight.unwrap_or(self.freq_weight);
+        self.undesirable_penalty = input
+            .undesirable_penalty
+            .unwrap_or(self.undesirable_penalty);
+        self.outlier_ratio = input.outlier_ratio.unwrap_or(self.outlier_ratio);
+        self.outlier_min_freq = input.outlier_min_freq.unwrap_or(self.outlier_min_freq);
+        self.language_penalty = input.language_penalty.unwrap_or(self.language_penalty);
+
+        if let Some(undesirable) = input.undesirable {
+            self.undesirable = undesirable
+                .into_iter()
+                .map(|token| token.to_lowercase())
+                .collect();
+        }
+
+        self
+    }
+
+    ///
+    /// Priority added to names of a given source
+    ///
+    pub fn source(&self, source: &Option<Source>) -> i8 {
+        match source {
+            Some(Source::Address) => self.address,
+            Some(Source::Network) => self.network,
+            Some(Source::Generated) => self.generated,
+            None => 0,
+        }
+    }
+
+    ///
+    /// Priority added to names of a given language, penalizing names
+    /// known to be in another language than the preferred language
+    ///
+    pub fn language(&self, language: &Option<String>) -> i8 {
+        match (&self.language, language) {
+            (Some(preferred), Some(language)) => {
+                if preferred == &language.to_lowercase() {
+                    0
+                } else {
+                    -self.language_penalty
+                }
+            }
+            _ => 0,
+        }
+    }
+
+    ///
+    /// Detects less desireable feature names
+    ///
+    pub fn is_undesireable(&self, tokenized: &Vec<Tokenized>) -> bool {
+        tokenized
+            .iter()
+            .any(|tk| self.undesirable.contains(&tk.token))
+    }
+
+    ///
+    /// Score used to order names, highest first
+    ///
+    pub fn score(&self, name: &Name, total_freq: i64) -> f64 {
+        if total_freq == 0 {
+            return name.priority as f64;
+        }
+
+        name.priority as f64 + self.freq_weight * (name.freq as f64 / total_freq as f64)
+    }
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Policy::new("")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Tokens;
+
+    #[test]
+    fn test_policy_new() {
+        assert_eq!(Policy::new("DE").undesirable, Vec::<String>::new());
+        assert_eq!(Policy::new("US").undesirable.len(), UNDESIREABLE.len());
+        assert_eq!(Policy::new("US").address, -1);
+    }
+
+    #[test]
+    fn test_policy_load() {
+        let path = std::env::temp_dir().join("pt2itp-policy.json");
+        std::fs::write(
+            &path,
+            r#"{ "sources": { "address": 0 }, "freq_weight": 2.5, "undesirable": ["Spur"], "languages": { "CA": "en", "CA-QC": "FR" } }"#,
+        )
+        .unwrap();
+
+        let policy = Policy::load("CA", &None, &path.to_str().unwrap().to_string()).unwrap();
+
+        assert_eq!(
+            policy,
+            Policy {
+                address: 0,
+                network: 0,
+                generated: 0,
+                freq_weight: 2.5,
+                undesirable: vec![String::from("spur")],
+                undesirable_penalty: 1,
+                outlier_ratio: 0.08,
+                outlier_min_freq: 10,
+                language: Some(String::from("en")),
+                language_penalty: 1,
+            }
+        );
+
+        let policy = Policy::load(
+            "CA",
+            &Some(String::from("QC")),
+            &path.to_str().unwrap().to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(policy.language, Some(String::from("fr")));
+        assert_eq!(policy.language(&Some(String::from("fr"))), 0);
+        assert_eq!(policy.language(&Some(String::from("en"))), -1);
+        assert_eq!(policy.language(&None), 0);
+
+        let policy = Policy::load(
+            "US",
+            &Some(String::from("QC")),
+            &path.to_str().unwrap().to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(policy.language, None);
+        assert_eq!(policy.language(&Some(String::from("en"))), 0);
+
+        let mut languages = HashMap::new();
+        languages.insert(String::from("CA-QC"), String::from("FR"));
+        let policy = Policy::resolve(
+            "CA",
+            &Some(String::from("QC")),
+            InputPolicy {
+                languages: Some(languages),
+                ..InputPolicy::default()
+            },
+        );
+
+        assert_eq!(policy.language, Some(String::from("fr")));
+        assert_eq!(policy.undesirable.len(), UNDESIREABLE.len());
+
+        std::fs::write(&path, r#"{ "freq_weight": "heavy" }"#).unwrap();
+        assert!(Policy::load("US", &None, &path.to_str().unwrap().to_string()).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(Policy::load("US", &None, &path.to_str().unwrap().to_string()).is_err());
+    }
+
+    #[test]
+    fn test_policy_is_undesireable() {
+        let tokens = Tokens::generate(vec![String::from("en")]).unwrap();
+        let policy = Policy::new("US");
+
+        assert_eq!(
+            policy.is_undesireable(&tokens.process(&String::from("Main St NE"), &String::from(""))),
+            false
+        );
+        assert_eq!(
+            policy.is_undesireable(
+                &tokens.process(&String::from("Main St NE Ext 25"), &String::from(""))
+            ),
+            true
+        );
+        assert_eq!(
+            policy.is_undesireable(
+                &tokens.process(&String::from("Main St NE Connector 25"), &String::from(""))
+            ),
+            true
+        );
+        assert_eq!(
+            policy.is_undesireable(
+                &tokens.process(&String::from("Main St NE Unit 25"), &String::from(""))
+            ),
+            true
+        );
+        assert_eq!(
+            policy.is_undesireable(
+                &tokens.process(&String::from("Main St NE Apartment 25"), &String::from(""))
+            ),
+            true
+        );
+        assert_eq!(
+            policy.is_undesireable(
+                &tokens.process(&String::from("Main St NE Shelby Apts"), &String::from(""))
+            ),
+            true
+        );
+        assert_eq!(
+            policy.is_undesireable(
+                &tokens.process(&String::from("Main St NE Suite 25"), &String::from(""))
+            ),
+            true
+        );
+        assert_eq!(
+            policy.is_undesireable(
+                &tokens.process(&String::from("Main St NE Lot 25"), &String::from(""))
+            ),
+            true
+        );
+    }
+}
@@ -118,7 +118,15 @@ fn check_substring(token_list_one: Vec<String>, mut token_list_two: Vec<String>)
 /// being matched with a slightly less desirable match, usually due to data
 /// reasons.
 ///
-pub fn linker(primary: Link, mut potentials: Vec<Link>, strict: bool) -> Option<LinkResult> {
+/// The country decides which numbered & route names are compared, ie: A40 is
+/// only a road number in GB & IE
+///
+pub fn linker(
+    primary: Link,
+    mut potentials: Vec<Link>,
+    strict: bool,
+    country: &str,
+) -> Option<LinkResult> {
     for name in &primary.names.names {
         let tokenized = name.tokenized_string();
         let tokenless = name.tokenless_string();
@@ -169,10 +177,10 @@ pub fn linker(primary: Link, mut potentials: Vec<Link>, strict: bool) -> Option<
 
                 // Don't bother considering if both addr and network are a numbered street that
                 // doesn't match (1st != 11th)
-                let name_numbered = is_numbered(name);
-                let name_routish = is_routish(name);
-                if name_numbered.is_some() && name_numbered != is_numbered(potential_name)
-                    || name_routish.is_some() && name_routish != is_routish(potential_name)
+                let name_numbered = is_numbered(name, country);
+                let name_routish = is_routish(name, country);
+                if name_numbered.is_some() && name_numbered != is_numbered(potential_name, country)
+                    || name_routish.is_some() && name_routish != is_routish(potential_name, country)
                 {
                     continue;
                 }
@@ -303,7 +311,7 @@ macro_rules! build_lang_context {
         Context::new(
             String::from($language),
             None,
-            Tokens::generate(vec![String::from($language)]),
+            Tokens::generate(vec![String::from($language)]).unwrap(),
         );
     };
 }
@@ -317,7 +325,7 @@ macro_rules! assert_linker_eq {
         let a = Link::new(1, &a_name);
         let b = vec![Link::new(2, &b_name)];
         assert_eq!(
-            linker(a, b, $strict_mode),
+            linker(a, b, $strict_mode, &context.country),
             Some(LinkResult::new(2, $expected_return))
         );
     };
@@ -331,7 +339,7 @@ macro_rules! assert_linker_no_match {
         let b_name = Names::new(vec![Name::new($name_b, 0, None, &context)], &context);
         let a = Link::new(1, &a_name);
         let b = vec![Link::new(2, &b_name)];
-        assert_eq!(linker(a, b, $strict_mode), None)
+        assert_eq!(linker(a, b, $strict_mode, &context.country), None)
     };
 }
 
@@ -427,8 +435,9 @@ mod tests {
         let context = Context::new(
             String::from("us"),
             None,
-            Tokens::new(tokens, regex_tokens, multi_tokens),
+            Tokens::new(tokens, regex_tokens, multi_tokens).unwrap(),
         );
+        let country = context.country.as_str();
 
         // === Intentional Matches ===
         // The following tests should match one of the given potential matches
@@ -559,7 +568,10 @@ mod tests {
                 Link::new(42, &b_name41),
                 Link::new(43, &b_name42),
             ];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(14, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(14, 100.0))
+            );
         }
 
         /*
@@ -590,7 +602,10 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_1_name), Link::new(3, &b_2_name)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 100.0))
+            );
         }
 
         {
@@ -598,7 +613,10 @@ mod tests {
             let b_name = Names::new(vec![Name::new("Main Street", 0, None, &context)], &context);
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 100.0))
+            );
         }
 
         {
@@ -609,7 +627,10 @@ mod tests {
             let b_name = Names::new(vec![Name::new("St Peter St", 0, None, &context)], &context);
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 100.0))
+            );
         }
 
         {
@@ -617,7 +638,10 @@ mod tests {
             let b_name = Names::new(vec![Name::new("Maim Street", 0, None, &context)], &context);
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 85.71)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 85.71))
+            );
         }
 
         {
@@ -631,7 +655,10 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 98.08)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 98.08))
+            );
         }
 
         {
@@ -645,7 +672,10 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 92.11)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 92.11))
+            );
         }
 
         {
@@ -663,7 +693,10 @@ mod tests {
                 Link::new(4, &b_name3),
                 Link::new(5, &b_name4),
             ];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 100.0))
+            );
         }
 
         {
@@ -681,7 +714,10 @@ mod tests {
                 Link::new(4, &b_name3),
                 Link::new(5, &b_name4),
             ];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 100.0))
+            );
         }
 
         {
@@ -692,7 +728,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name1), Link::new(3, &b_name2)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 80.0)));
+            assert_eq!(linker(a, b, false, country), Some(LinkResult::new(2, 80.0)));
         }
 
         {
@@ -706,7 +742,10 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name1), Link::new(3, &b_name2)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 77.78)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 77.78))
+            );
         }
 
         {
@@ -725,7 +764,10 @@ mod tests {
                 Link::new(3, &b_name2),
                 Link::new(4, &b_name3),
             ];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 77.78)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 77.78))
+            );
         }
 
         {
@@ -744,7 +786,10 @@ mod tests {
                 Link::new(3, &b_name2),
                 Link::new(4, &b_name3),
             ];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(4, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(4, 100.0))
+            );
         }
 
         {
@@ -759,7 +804,10 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 85.71)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 85.71))
+            );
         }
 
         {
@@ -775,7 +823,10 @@ mod tests {
                 Link::new(3, &b_name2),
                 Link::new(4, &b_name3),
             ];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(2, 85.71)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(2, 85.71))
+            );
         }
 
         {
@@ -799,7 +850,10 @@ mod tests {
                 Link::new(4, &b_name3),
                 Link::new(5, &b_name4),
             ];
-            assert_eq!(linker(a, b, false), Some(LinkResult::new(3, 100.0)));
+            assert_eq!(
+                linker(a, b, false, country),
+                Some(LinkResult::new(3, 100.0))
+            );
         }
 
         // === Intentional Non-Matches ===
@@ -816,7 +870,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), None);
+            assert_eq!(linker(a, b, false, country), None);
         }
 
         {
@@ -830,7 +884,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), None);
+            assert_eq!(linker(a, b, false, country), None);
         }
 
         {
@@ -844,7 +898,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), None);
+            assert_eq!(linker(a, b, false, country), None);
         }
 
         {
@@ -858,7 +912,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), None);
+            assert_eq!(linker(a, b, false, country), None);
         }
 
         {
@@ -872,7 +926,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), None);
+            assert_eq!(linker(a, b, false, country), None);
         }
 
         {
@@ -886,7 +940,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), None);
+            assert_eq!(linker(a, b, false, country), None);
         }
 
         {
@@ -897,7 +951,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, false), None);
+            assert_eq!(linker(a, b, false, country), None);
         }
 
         // === Intentional Strict Matches ===
@@ -909,7 +963,7 @@ mod tests {
             let b_name = Names::new(vec![Name::new("Main Street", 0, None, &context)], &context);
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 100.0)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 100.0)));
         }
 
         {
@@ -920,7 +974,7 @@ mod tests {
             let b_name = Names::new(vec![Name::new("St Peter St", 0, None, &context)], &context);
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 100.0)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 100.0)));
         }
 
         {
@@ -932,7 +986,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 93.75)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 93.75)));
         }
 
         {
@@ -944,7 +998,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 90.0)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 90.0)));
         }
 
         {
@@ -953,7 +1007,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 86.36)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 86.36)));
         }
 
         {
@@ -962,7 +1016,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 86.36)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 86.36)));
         }
 
         {
@@ -971,7 +1025,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 90.0)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 90.0)));
         }
 
         {
@@ -986,7 +1040,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name1)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 85.71)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 85.71)));
         }
 
         {
@@ -995,7 +1049,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 80.77)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 80.77)));
         }
 
         {
@@ -1007,7 +1061,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), Some(LinkResult::new(2, 78.57)));
+            assert_eq!(linker(a, b, true, country), Some(LinkResult::new(2, 78.57)));
         }
 
         // === Intentional Strict Non-Matches ===
@@ -1023,7 +1077,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), None);
+            assert_eq!(linker(a, b, true, country), None);
         }
 
         {
@@ -1037,7 +1091,7 @@ mod tests {
             );
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), None);
+            assert_eq!(linker(a, b, true, country), None);
         }
 
         {
@@ -1046,7 +1100,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), None);
+            assert_eq!(linker(a, b, true, country), None);
         }
 
         {
@@ -1055,7 +1109,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), None);
+            assert_eq!(linker(a, b, true, country), None);
         }
 
         {
@@ -1070,7 +1124,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), None);
+            assert_eq!(linker(a, b, true, country), None);
         }
 
         {
@@ -1082,7 +1136,7 @@ mod tests {
 
             let a = Link::new(1, &a_name);
             let b = vec![Link::new(2, &b_name)];
-            assert_eq!(linker(a, b, true), None);
+            assert_eq!(linker(a, b, true, country), None);
         }
     }
 
@@ -21,7 +21,8 @@ test('classify (dataset)', (t) => {
             input: './test/fixtures/classify.geojson',
             output: '/tmp/classifyout.geojson',
             parcels: './test/fixtures/classify_parcels.geojson',
-            buildings: './test/fixtures/classify_buildings.geojson'
+            buildings: './test/fixtures/classify_buildings.geojson',
+            interpolated: ['pt2itp']
         });
     }, 'classify runs without err');
 
@@ -41,6 +42,43 @@ test('classify (dataset)', (t) => {
     t.end();
 });
 
+test('classify (building tolerance)', (t) => {
+    try {
+        fs.unlinkSync('/tmp/classifyout.geojson');
+    } catch (err) {
+        console.log('ok - no tmp files to clear');
+    }
+
+    t.doesNotThrow(() => {
+        classify({
+            db: 'pt_test',
+            input: './test/fixtures/classify.geojson',
+            output: '/tmp/classifyout.geojson',
+            parcels: './test/fixtures/classify_parcels.geojson',
+            buildings: './test/fixtures/classify_buildings.geojson',
+            building_tolerance: 40
+        });
+    }, 'classify runs without err');
+
+    const rl = new ReadLine('/tmp/classifyout.geojson');
+
+    const output = {};
+    let line = rl.next();
+    while (line) {
+        line = JSON.parse(line);
+
+        output[line.properties.number] = line.properties.accuracy;
+        line = rl.next();
+    }
+
+    t.equals(output[6], 'rooftop', '6 is within 40m of a building');
+    t.equals(output[7], 'rooftop', '7 is within 40m of a building');
+    t.equals(output[8], 'point', '8 is not within 40m of a building');
+    t.equals(output[9], 'point', '9 is not within 40m of a building');
+
+    t.end();
+});
+
 test('classify (hecate)', (t) => {
     try {
         fs.unlinkSync('/tmp/classifyout.geojson');
@@ -225,3 +263,47 @@ test('classify (escaping)', (t) => {
 
     t.end();
 });
+
+test('classify (snap)', (t) => {
+    try {
+        fs.unlinkSync('/tmp/classifyout.geojson');
+    } catch (err) {
+        console.log('ok - no tmp files to clear');
+    }
+
+    t.doesNotThrow(() => {
+        classify({
+            db: 'pt_test',
+            hecate: true,
+            snap: true,
+            input: './test/fixtures/classify_hecate.geojson',
+            output: '/tmp/classifyout.geojson',
+            parcels: './test/fixtures/classify_parcels.geojson',
+            buildings: './test/fixtures/classify_snap_buildings.geojson'
+        });
+    }, 'classify runs without err');
+
+    const rl = new ReadLine('/tmp/classifyout.geojson');
+
+    const output = {};
+    let line = rl.next();
+    while (line) {
+        line = JSON.parse(line);
+
+        output[line.id] = line;
+        line = rl.next();
+    }
+
+    t.equals(output[7].action, 'modify', '7 is modified');
+    t.equals(output[7].properties.accuracy, 'rooftop', '7 is upgraded to rooftop');
+    t.deepEquals(output[7].properties.snapped_from, [-79.376530498, 38.833649772], '7 retains original position');
+
+    const coords = output[7].geometry.coordinates;
+    t.ok(coords[0] > -79.37650 && coords[0] < -79.37635, '7 snapped to the largest building (lon)');
+    t.ok(coords[1] > 38.83345 && coords[1] < 38.83360, '7 snapped to the largest building (lat)');
+
+    t.equals(output[6].properties.accuracy, 'parcel', '6 has no building in its parcel');
+    t.equals(output[6].properties.snapped_from, undefined, '6 is not snapped');
+
+    t.end();
+});
@@ -221,3 +221,251 @@ test('dedupe (hecate)', (t) => {
 
     t.end();
 });
+
+test('dedupe (fuzzy)', (t) => {
+    try {
+        fs.unlinkSync('/tmp/dedupeout.geojson');
+    } catch (err) {
+        console.log('ok - no tmp files to clear');
+    }
+
+    t.doesNotThrow(() => {
+        dedupe({
+            db: 'pt_test',
+            hecate: false,
+            fuzzy: true,
+            radius: 10,
+            input: path.resolve(__dirname, './fixtures/dedupe-fuzzy.geojson'),
+            output: '/tmp/dedupeout.geojson',
+            context: {
+                country: 'us',
+                region: 'dc',
+                languages: ['en']
+            }
+        });
+    }, 'dedupe runs without err');
+
+    const rl = new ReadLine('/tmp/dedupeout.geojson');
+
+    const output = {};
+    let line = rl.next();
+    while (line) {
+        line = JSON.parse(line);
+
+        output[line.id] = line;
+        line = rl.next();
+    }
+
+    t.deepEquals(Object.keys(output), [
+        '1', // ID 1 & 2 should be collapsed to only ID 1 (Main St vs Main Street, 10A vs 10 a, ~5m apart)
+        '3', // ID 3 should be output - 10 vs 10a for number
+        '4', // ID 4 should be output - Main Ave does not link to Main St
+        '5' // ID 5 should be output - outside of the fuzzy radius
+    ], 'output ids as expected');
+
+    t.end();
+});
+
+test('dedupe (fuzzy, hecate)', (t) => {
+    try {
+        fs.unlinkSync('/tmp/dedupeout.geojson');
+    } catch (err) {
+        console.log('ok - no tmp files to clear');
+    }
+
+    t.doesNotThrow(() => {
+        dedupe({
+            db: 'pt_test',
+            hecate: true,
+            fuzzy: true,
+            input: path.resolve(__dirname, './fixtures/dedupe-fuzzy.geojson'),
+            output: '/tmp/dedupeout.geojson',
+            context: {
+                country: 'us',
+                region: 'dc',
+                languages: ['en']
+            }
+        });
+    }, 'dedupe runs without err');
+
+    const rl = new ReadLine('/tmp/dedupeout.geojson');
+
+    const output = {};
+    let line = rl.next();
+    while (line) {
+        line = JSON.parse(line);
+
+        output[line.id] = line;
+        line = rl.next();
+    }
+
+    t.deepEquals(Object.keys(output), ['2'], 'output ids as expected');
+
+    t.deepEquals(output[2], {
+        id: 2,
+        version: 0,
+        type: 'Feature',
+        action: 'delete',
+        properties: {
+            number: '10a',
+            source: 'random',
+            'dedupe:tier': 'fuzzy',
+            street: [{ display: 'Main Street', priority: -1 }]
+        },
+        geometry: {
+            type: 'Point',
+            coordinates: [-77.481789613, 37.500529520]
+        }
+    }, 'feature 2');
+
+    t.end();
+});
+
+test('dedupe (survivor: source, merge)', (t) => {
+    try {
+        fs.unlinkSync('/tmp/dedupeout.geojson');
+    } catch (err) {
+        console.log('ok - no tmp files to clear');
+    }
+
+    t.doesNotThrow(() => {
+        dedupe({
+            db: 'pt_test',
+            hecate: false,
+            survivor: 'source',
+            sources: ['random'],
+            merge: true,
+            input: path.resolve(__dirname, './fixtures/dedupe.geojson'),
+            output: '/tmp/dedupeout.geojson',
+            context: {
+                country: 'us',
+                region: 'dc',
+                languages: ['en']
+            }
+        });
+    }, 'dedupe runs without err');
+
+    const rl = new ReadLine('/tmp/dedupeout.geojson');
+
+    const output = {};
+    let line = rl.next();
+    while (line) {
+        line = JSON.parse(line);
+
+        output[line.id] = line;
+        line = rl.next();
+    }
+
+    t.deepEquals(Object.keys(output), [
+        '2', // ID 1,2,7 & 8 should be collapsed to ID 2 (preferred source)
+        '3', '4',
+        '5', '6'
+    ], 'output ids as expected');
+
+    t.deepEquals(output[2], {
+        id: 2,
+        type: 'Feature',
+        properties: {
+            number: '123',
+            source: 'random',
+            random: 'property',
+            street: [{ display: 'Main St', priority: -1 }]
+        },
+        geometry: {
+            type: 'Point',
+            coordinates: [-77.481846213, 37.500529520]
+        }
+    }, 'feature 2 has merged props');
+
+    t.end();
+});
+
+test('dedupe (buildings)', (t) => {
+    try {
+        fs.unlinkSync('/tmp/dedupeout.geojson');
+    } catch (err) {
+        console.log('ok - no tmp files to clear');
+    }
+
+    t.doesNotThrow(() => {
+        dedupe({
+            db: 'pt_test',
+            hecate: true,
+            input: path.resolve(__dirname, './fixtures/dedupe-buildings.geojson'),
+            buildings: path.resolve(__dirname, './fixtures/dedupe-buildings-polygons.geojson'),
+            output: '/tmp/dedupeout.geojson',
+            context: {
+                country: 'us',
+                region: 'dc',
+                languages: ['en']
+            }
+        });
+    }, 'dedupe runs without err');
+
+    const rl = new ReadLine('/tmp/dedupeout.geojson');
+
+    const output = {};
+    let line = rl.next();
+    while (line) {
+        line = JSON.parse(line);
+
+        output[line.id] = line;
+        line = rl.next();
+    }
+
+    t.deepEquals(Object.keys(output), [
+        '2' // ID 1 & 2 are in the same building ~15m apart (leaving 1)
+        // ID 3 should be ignored - same building but 125 vs 123 for number
+        // ID 4 should be ignored - same number/street but outside of the building
+    ], 'output ids as expected');
+
+    t.equals(output[2].action, 'delete', 'feature 2 deleted');
+    t.equals(output[2].properties['dedupe:tier'], 'building', 'feature 2 caught by building tier');
+
+    t.end();
+});
+
+test('dedupe (report)', (t) => {
+    try {
+        fs.unlinkSync('/tmp/dedupeout.geojson');
+        fs.unlinkSync('/tmp/dedupereport.jsonl');
+    } catch (err) {
+        console.log('ok - no tmp files to clear');
+    }
+
+    t.doesNotThrow(() => {
+        dedupe({
+            db: 'pt_test',
+            hecate: false,
+            input: path.resolve(__dirname, './fixtures/dedupe.geojson'),
+            output: '/tmp/dedupeout.geojson',
+            report: '/tmp/dedupereport.jsonl',
+            context: {
+                country: 'us',
+                region: 'dc',
+                languages: ['en']
+            }
+        });
+    }, 'dedupe runs without err');
+
+    const rl = new ReadLine('/tmp/dedupereport.jsonl');
+
+    const report = [];
+    let line = rl.next();
+    while (line) {
+        report.push(JSON.parse(line));
+        line = rl.next();
+    }
+
+    t.deepEquals(report, [{
+        survivor: 1,
+        members: [
+            { id: 1, source: 'openaddresses', distance: 0, tier: null, survivor: true },
+            { id: 2, source: 'random', distance: 0, tier: 'exact', survivor: false },
+            { id: 7, source: 'openaddresses', distance: 0, tier: 'exact', survivor: false },
+            { id: 8, source: 'openaddresses', distance: 0, tier: 'exact', survivor: false }
+        ]
+    }], 'single duplicate group reported');
+
+    t.end();
+});
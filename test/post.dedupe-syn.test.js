@@ -126,3 +126,33 @@ tape('dedupe feature names', (t) => {
 
     t.end();
 });
+
+tape('dedupe feature names with a ranking policy', (t) => {
+    const names = [
+        { freq: 20, source: 'Network', display: 'East Hackberry Drive', priority: 0, tokenized: [{ token: 'e', 'token_type': 'Cardinal' }, { token: 'hackberry', 'token_type': null }, { token: 'dr', 'token_type': 'Way' }] },
+        { freq: 20, source: 'Address', display: 'E Hackberry Dr', priority: 0, tokenized: [{ token: 'e', 'token_type': 'Cardinal' }, { token: 'hackberry', 'token_type': null }, { token: 'dr', 'token_type': 'Way' }] },
+        { freq: 1, source: 'Address', display: 'W Hackberry Dr', priority: 0, tokenized: [{ token: 'w', 'token_type': 'Cardinal' }, { token: 'hackberry', 'token_type': null }, { token: 'dr', 'token_type': 'Way' }] },
+        { freq: 1, source: 'Address', display: 'Hackberry Dr', priority: 0, tokenized: [{ token: 'hackberry', 'token_type': null }, { token: 'dr', 'token_type': 'Way' }] }
+    ];
+
+    t.deepEqual(
+        dedupe_syn(names, { outlier_ratio: 0 }),
+        ['East Hackberry Drive', 'W Hackberry Dr', 'Hackberry Dr'],
+        'outlier ratio is configurable'
+    );
+
+    t.deepEqual(
+        dedupe_syn([
+            { freq: 1, source: 'Network', display: 'Main St', priority: 0, tokenized: [{ token: 'main', 'token_type': null }, { token: 'st', 'token_type': 'Way' }] },
+            { freq: 9, source: 'Address', display: 'Oak St', priority: -1, tokenized: [{ token: 'oak', 'token_type': null }, { token: 'st', 'token_type': 'Way' }] }
+        ], { freq_weight: 2 }),
+        ['Oak St', 'Main St'],
+        'frequent names can outrank higher priority names'
+    );
+
+    t.throws(() => {
+        dedupe_syn(names, { freq_weight: 'heavy' });
+    }, /unable to assign policy/, 'policy must be valid');
+
+    t.end();
+});
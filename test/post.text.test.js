@@ -95,5 +95,21 @@ test('Post: Text', (t) => {
         }
     }, 'dedupe tokens, excessive synonyms');
 
+    t.deepEquals(post({
+        properties: {
+            'carmen:text': [
+                { freq: 1, display: 'Rue Principale', priority: 0, language: 'fr', tokenized: [{ token: 'rue', token_type: 'Way' }, { token: 'principale', token_type: null }] },
+                { freq: 1, display: 'Main Street', priority: -1, language: 'en', tokenized: [{ token: 'main', token_type: null }, { token: 'st', token_type: 'Way' }] },
+                { freq: 1, display: 'Highway 20', priority: -1, tokenized: [{ token: 'highway', token_type: null }, { token: '20', token_type: null }] }
+            ]
+        }
+    }), {
+        properties: {
+            'carmen:text': 'Rue Principale,Main Street,Highway 20',
+            'carmen:text_fr': 'Rue Principale',
+            'carmen:text_en': 'Main Street'
+        }
+    }, 'output language tagged names in carmen:text_xx');
+
     t.end();
 });
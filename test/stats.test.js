@@ -3,6 +3,8 @@
 const stats = require('../native/index.node').stats;
 const test = require('tape');
 const path = require('path');
+const fs = require('fs');
+const os = require('os');
 
 test('Stats - MultiPoint Orphan', (t) => {
     const res = stats({
@@ -17,6 +19,17 @@ test('Stats - MultiPoint Orphan', (t) => {
         intersections: 0,
         address_orphans: 1,
         network_orphans: 0,
+        ranges: {
+            length: 0,
+            length_ranged: 0,
+            length_unranged: 0,
+            parity_consistent: 0,
+            parity_inconsistent: 0,
+            overlaps: 0,
+            addresses_in_range: 0,
+            addresses_out_of_range: 0,
+            addresses_unranged: 0
+        },
         bounds: {}
     }, 'has 2 addresses');
 
@@ -36,6 +49,17 @@ test('Stats - GeometryCollection Orphan', (t) => {
         intersections: 0,
         address_orphans: 1,
         network_orphans: 0,
+        ranges: {
+            length: 0,
+            length_ranged: 0,
+            length_unranged: 0,
+            parity_consistent: 0,
+            parity_inconsistent: 0,
+            overlaps: 0,
+            addresses_in_range: 0,
+            addresses_out_of_range: 0,
+            addresses_unranged: 0
+        },
         bounds: {}
     }, 'has 2 addresses');
 
@@ -55,6 +79,17 @@ test('Stats - MultiLine', (t) => {
         intersections: 0,
         address_orphans: 2,
         network_orphans: 0,
+        ranges: {
+            length: 0,
+            length_ranged: 0,
+            length_unranged: 0,
+            parity_consistent: 0,
+            parity_inconsistent: 0,
+            overlaps: 0,
+            addresses_in_range: 0,
+            addresses_out_of_range: 0,
+            addresses_unranged: 0
+        },
         bounds: {}
     }, 'has 4 addresses');
 
@@ -74,6 +109,17 @@ test('Stats - Cluster', (t) => {
         intersections: 1,
         address_orphans: 0,
         network_orphans: 0,
+        ranges: {
+            length: 314475,
+            length_ranged: 0,
+            length_unranged: 314475,
+            parity_consistent: 0,
+            parity_inconsistent: 0,
+            overlaps: 0,
+            addresses_in_range: 0,
+            addresses_out_of_range: 0,
+            addresses_unranged: 2
+        },
         bounds: {}
     }, 'has 1 cluster');
 
@@ -93,6 +139,17 @@ test('Stats - Network Orphan', (t) => {
         intersections: 0,
         address_orphans: 0,
         network_orphans: 1,
+        ranges: {
+            length: 314475,
+            length_ranged: 0,
+            length_unranged: 314475,
+            parity_consistent: 0,
+            parity_inconsistent: 0,
+            overlaps: 0,
+            addresses_in_range: 0,
+            addresses_out_of_range: 0,
+            addresses_unranged: 0
+        },
         bounds: {}
     }, 'has 1 cluster');
 
@@ -112,6 +169,17 @@ test('Stats - Invalid', (t) => {
         intersections: 0,
         address_orphans: 0,
         network_orphans: 0,
+        ranges: {
+            length: 0,
+            length_ranged: 0,
+            length_unranged: 0,
+            parity_consistent: 0,
+            parity_inconsistent: 0,
+            overlaps: 0,
+            addresses_in_range: 0,
+            addresses_out_of_range: 0,
+            addresses_unranged: 0
+        },
         bounds: {}
     }, 'has 1 cluster');
 
@@ -132,6 +200,7 @@ test('Stats - Real World Data', (t) => {
         'intersections',
         'address_orphans',
         'network_orphans',
+        'ranges',
         'bounds'
     ].sort());
 
@@ -148,14 +217,31 @@ test('Stats - Real World Data', (t) => {
             t.deepEquals(res.bounds[bound], {
                 addresses: 2,
                 intersections: 1,
+                clusters: 1,
+                address_orphans: 0,
+                network_orphans: 0,
+                ranges: {
+                    length: 344,
+                    length_ranged: 0,
+                    length_unranged: 344,
+                    parity_consistent: 0,
+                    parity_inconsistent: 0,
+                    overlaps: 0,
+                    addresses_in_range: 0,
+                    addresses_out_of_range: 0,
+                    addresses_unranged: 2
+                },
                 names: ['US Route 101'],
                 synonyms: ['101', 'US Route', 'US Route 101'],
                 custom: {
                     postcodes: 1,
                     accuracy: {
                         rooftop: 1,
+                        entrance: 0,
                         parcel: 0,
-                        point: 1
+                        point: 1,
+                        interpolated: 0,
+                        other: 0
                     }
                 }
             }, 'DC should have data');
@@ -163,14 +249,31 @@ test('Stats - Real World Data', (t) => {
             t.deepEquals(res.bounds[bound], {
                 addresses: 0,
                 intersections: 0,
+                clusters: 0,
+                address_orphans: 0,
+                network_orphans: 0,
+                ranges: {
+                    length: 0,
+                    length_ranged: 0,
+                    length_unranged: 0,
+                    parity_consistent: 0,
+                    parity_inconsistent: 0,
+                    overlaps: 0,
+                    addresses_in_range: 0,
+                    addresses_out_of_range: 0,
+                    addresses_unranged: 0
+                },
                 names: [],
                 synonyms: [],
                 custom: {
                     postcodes: 0,
                     accuracy: {
                         rooftop: 0,
+                        entrance: 0,
                         parcel: 0,
-                        point: 0
+                        point: 0,
+                        interpolated: 0,
+                        other: 0
                     }
                 }
             }, bound);
@@ -179,3 +282,95 @@ test('Stats - Real World Data', (t) => {
 
     t.end();
 });
+
+test('Stats - Ranges', (t) => {
+    const res = stats({
+        input: String(path.resolve(__dirname, 'fixtures/stats.range'))
+    });
+
+    t.deepEquals(res.ranges, {
+        length: 222,
+        length_ranged: 222,
+        length_unranged: 0,
+        parity_consistent: 3,
+        parity_inconsistent: 1,
+        overlaps: 1,
+        addresses_in_range: 1,
+        addresses_out_of_range: 1,
+        addresses_unranged: 0
+    }, 'has range metrics');
+
+    t.end();
+});
+
+test('Stats - Diff', (t) => {
+    const res = stats({
+        input: String(path.resolve(__dirname, 'fixtures/stats.orphan-double')),
+        previous: String(path.resolve(__dirname, 'fixtures/stats.orphan'))
+    });
+
+    t.deepEquals(res, {
+        feats: { before: 1, after: 2, delta: 1 },
+        clusters: { before: 0, after: 0, delta: 0 },
+        invalid: { before: 0, after: 0, delta: 0 },
+        addresses: { before: 2, after: 4, delta: 2 },
+        intersections: { before: 0, after: 0, delta: 0 },
+        address_orphans: { before: 1, after: 2, delta: 1 },
+        network_orphans: { before: 0, after: 0, delta: 0 },
+        bounds: {}
+    }, 'has 2 more addresses');
+
+    t.end();
+});
+
+test('Stats - Diff Real World Data', (t) => {
+    const res = stats({
+        input: String(path.resolve(__dirname, 'fixtures/stats.actual')),
+        previous: String(path.resolve(__dirname, 'fixtures/stats.actual')),
+        bounds: '/tmp/counties.geojson'
+    });
+
+    t.deepEquals(res.addresses, { before: 2, after: 2, delta: 0 });
+
+    t.deepEquals(res.bounds['11001'].addresses, { before: 2, after: 2, delta: 0 }, 'DC addresses unchanged');
+    t.deepEquals(res.bounds['11001'].clusters, { before: 1, after: 1, delta: 0 }, 'DC clusters unchanged');
+    t.deepEquals(res.bounds['11001'].names_added, [], 'DC no names added');
+    t.deepEquals(res.bounds['11001'].names_removed, [], 'DC no names removed');
+    t.deepEquals(res.bounds['11001'].accuracy.rooftop, { before: 1, after: 1, delta: 0 }, 'DC rooftop unchanged');
+
+    t.end();
+});
+
+test('Stats - Export', (t) => {
+    const csv = path.resolve(os.tmpdir(), 'stats-export.csv');
+    const geojson = path.resolve(os.tmpdir(), 'stats-export.geojson');
+
+    stats({
+        input: String(path.resolve(__dirname, 'fixtures/stats.actual')),
+        bounds: String(path.resolve(__dirname, 'fixtures/stats.bounds')),
+        csv: csv,
+        geojson: geojson
+    });
+
+    t.deepEquals(fs.readFileSync(csv, 'utf8').split('\n'), [
+        'name,addresses,intersections,clusters,address_orphans,network_orphans,names,synonyms,postcodes,postcode_coverage,rooftop,entrance,parcel,point,interpolated,other,length,length_ranged,addresses_in_range,addresses_out_of_range,addresses_unranged',
+        'Elsewhere,0,0,0,0,0,0,0,0,0.0,0,0,0,0,0,0,0,0,0,0,0',
+        '"Washington, DC",2,1,1,0,0,1,3,1,0.5,1,0,0,1,0,0,344,0,0,0,2',
+        ''
+    ], 'csv has a row per bound');
+
+    const feats = fs.readFileSync(geojson, 'utf8').trim().split('\n').map(JSON.parse);
+
+    t.equals(feats.length, 2, 'geojson has a feature per bound');
+    t.deepEquals(feats.map((feat) => feat.properties.name), ['Elsewhere', 'Washington, DC']);
+    t.equals(feats[1].geometry.type, 'MultiPolygon');
+    t.deepEquals(feats[1].geometry.coordinates, [[[[-77.01, 38.88], [-76.98, 38.88], [-76.98, 38.90], [-77.01, 38.90], [-77.01, 38.88]]]]);
+    t.equals(feats[1].properties.addresses, 2);
+    t.equals(feats[1].properties.postcode_coverage, 0.5);
+    t.equals(feats[1].properties.rooftop, 1);
+
+    fs.unlinkSync(csv);
+    fs.unlinkSync(geojson);
+
+    t.end();
+});
@@ -0,0 +1,43 @@
+'use strict';
+
+const tape = require('tape');
+const { tokenize_names } = require('../native/index.node');
+const Context = require('../lib/util/context');
+
+tape('tokenize_names', (t) => {
+    const context = new Context({ country: 'us', region: 'dc' });
+
+    t.deepEqual(tokenize_names([], context), []);
+
+    t.deepEqual(tokenize_names([
+        '5 Avenue',
+        { display: 'main avenue', priority: 1 }
+    ], context), [[{
+        display: '5 Avenue',
+        priority: 0,
+        source: 'Network',
+        tokenized: [{ token: '5', token_type: null }, { token: 'avenue', token_type: null }],
+        freq: 1,
+        tokenless: '5 avenue'
+    }, {
+        display: '5th Avenue',
+        priority: -1,
+        source: 'Generated',
+        tokenized: [{ token: '5th', token_type: null }, { token: 'avenue', token_type: null }],
+        freq: 1,
+        tokenless: '5th avenue'
+    }], [{
+        display: 'Main Avenue',
+        priority: 1,
+        source: 'Network',
+        tokenized: [{ token: 'main', token_type: null }, { token: 'avenue', token_type: null }],
+        freq: 1,
+        tokenless: 'main avenue'
+    }]], 'returns the names & generated synonyms of each input name');
+
+    t.throws(() => {
+        tokenize_names([{ priority: 1 }], context);
+    }, /invalid name/, 'names must have a display');
+
+    t.end();
+});